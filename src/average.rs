@@ -0,0 +1,166 @@
+use crate::{Motor, Pga, Vga};
+use crate::multivector::{Real, Zero};
+
+#[cfg(test)]
+mod tests;
+
+
+/// Bivectors of 3D VGA by bit representation, the coordinates of the tangent space of rotors.
+const ROTOR_TANGENT: [usize; 3] = [0b011, 0b101, 0b110];
+
+/// Bivectors of 3D PGA, `e12, e13, e23, e10, e20, e30`, the coordinates of the tangent space of motors.
+const MOTOR_TANGENT: [usize; 6] = [0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100];
+
+/// Means and spread of rotations.
+///
+/// The geodesic mean is the Karcher mean, minimizing the weighted sum of squared angles to the rotors: from
+/// the chordal mean, each step moves along the weighted mean of the logarithms `log(mean~ R_i)` in the
+/// tangent space until it vanishes. It is unique when the rotations lie within a quarter turn of each other.
+impl<T> Vga<T, 3> where
+T: Real,
+{
+    /// `weighted_chordal_mean` with equal weights.
+    pub fn chordal_mean(rotors: &[Self]) -> Option<Self> {
+        Self::weighted_chordal_mean(rotors, &vec![T::one(); rotors.len()])
+    }
+
+    /// The weighted sum of unit rotors, each with the sign that puts it on the side of the first, scaled
+    /// back to a unit rotor; `None` unless there are as many positive weights as rotors and the sum is not zero.
+    ///
+    /// This is close to the geodesic mean for rotations close to each other, at a fraction of the cost, and
+    /// equal to it for rotations placed symmetrically about it.
+    pub fn weighted_chordal_mean(rotors: &[Self], weights: &[T]) -> Option<Self> {
+        check_weights(rotors, weights)?;
+        let sum = rotors.iter().zip(weights).fold(Self::zero(), |s, (r, w)| {
+            let w = if r.reverse().geometric_product(&rotors[0]).scalar_part() < T::zero() { w.clone().neg() } else { w.clone() };
+            s + r.scale(&w)
+        });
+        if sum.norm_squared() <= T::zero() {
+            return None;
+        }
+        Some(sum.normalized())
+    }
+
+    /// `weighted_geodesic_mean` with equal weights.
+    pub fn geodesic_mean(rotors: &[Self]) -> Option<Self> {
+        Self::weighted_geodesic_mean(rotors, &vec![T::one(); rotors.len()])
+    }
+
+    /// The rotor minimizing `sum_i w_i |log(mean~ R_i)|^2`, with the same conditions as
+    /// `weighted_chordal_mean`.
+    pub fn weighted_geodesic_mean(rotors: &[Self], weights: &[T]) -> Option<Self> {
+        let total = check_weights(rotors, weights)?;
+        let mut mean = Self::weighted_chordal_mean(rotors, weights)?;
+        for _ in 0..64 {
+            let mut step = Self::zero();
+            for (r, w) in rotors.iter().zip(weights) {
+                step += rotor_tangent(&mean, r)?.scale(&(w.clone() / total.clone()));
+            }
+            mean = mean.geometric_product(&step.exp()).normalized();
+            if step.norm_squared().abs() <= T::epsilon() * T::epsilon() {
+                break;
+            }
+        }
+        Some(mean)
+    }
+
+    /// The weighted covariance `sum_i w_i b_i b_i^T / sum_i w_i` of the coefficients `b_i` on `e12, e13, e23`
+    /// of the bivectors `log(mean~ R_i)`, which are half the rotation vectors taking `mean` to each rotor.
+    pub fn covariance(rotors: &[Self], weights: &[T], mean: &Self) -> Option<Vec<Vec<T>>> {
+        let total = check_weights(rotors, weights)?;
+        let tangents = rotors.iter()
+            .map(|r| rotor_tangent(mean, r).map(|b| ROTOR_TANGENT.map(|bits| b.coefficient(bits).clone())))
+            .collect::<Option<Vec<_>>>()?;
+        Some(covariance(&tangents, weights, &total))
+    }
+}
+
+/// Means and spread of rigid motions, as `Vga` does for rotations, for the motors of `Motor` embedded in
+/// 3D PGA.
+///
+/// The chordal mean is dual quaternion linear blending, and the geodesic mean, with the logarithm of screw
+/// motions, is invariant under moving all the motors by the same motion, unlike averaging rotations and
+/// translations separately.
+impl<T> Pga<T, 3> where
+T: Real,
+{
+    /// `weighted_chordal_mean` with equal weights.
+    pub fn chordal_mean(motors: &[Self]) -> Option<Self> {
+        Self::weighted_chordal_mean(motors, &vec![T::one(); motors.len()])
+    }
+
+    /// The weighted sum of motors, each with the sign that puts its rotation on the side of the first,
+    /// normalized to a rigid motion, with the same conditions as `Vga::weighted_chordal_mean`.
+    pub fn weighted_chordal_mean(motors: &[Self], weights: &[T]) -> Option<Self> {
+        check_weights(motors, weights)?;
+        let first = Motor::from_even(&motors[0]);
+        let sum = motors.iter().zip(weights).fold(Motor::zero(), |s, (m, w)| {
+            let m = Motor::from_even(m);
+            let side = m.coefficients().iter().zip(first.coefficients()).take(4).fold(T::zero(), |d, (x, y)| d + x.clone() * y.clone());
+            let w = if side < T::zero() { w.clone().neg() } else { w.clone() };
+            s + Motor::from(m.coefficients().clone().map(|x| x * w.clone()))
+        });
+        if sum.coefficients().iter().take(4).all(|x| *x == T::zero()) {
+            return None;
+        }
+        Some(Self::from(sum.normalized()))
+    }
+
+    /// `weighted_geodesic_mean` with equal weights.
+    pub fn geodesic_mean(motors: &[Self]) -> Option<Self> {
+        Self::weighted_geodesic_mean(motors, &vec![T::one(); motors.len()])
+    }
+
+    /// The motor minimizing `sum_i w_i |log(mean~ M_i)|^2` in the coefficients of the bivectors, with the
+    /// same conditions as `weighted_chordal_mean`.
+    pub fn weighted_geodesic_mean(motors: &[Self], weights: &[T]) -> Option<Self> {
+        let total = check_weights(motors, weights)?;
+        let mut mean = Motor::from_even(&Self::weighted_chordal_mean(motors, weights)?);
+        let motors: Vec<Motor<T>> = motors.iter().map(Motor::from_even).collect();
+        for _ in 0..64 {
+            let step = motors.iter().zip(weights).fold(Self::zero(), |s, (m, w)| {
+                s + (&mean.reverse() * m).log().scale(&(w.clone() / total.clone()))
+            });
+            mean = (&mean * &Motor::exp(&step)).normalized();
+            if MOTOR_TANGENT.iter().all(|&bits| step.coefficient(bits).abs() <= T::epsilon()) {
+                break;
+            }
+        }
+        Some(Self::from(mean))
+    }
+
+    /// The weighted covariance, as for `Vga::covariance`, of the coefficients on `e12, e13, e23, e10, e20, e30`
+    /// of the bivectors `log(mean~ M_i)`.
+    pub fn covariance(motors: &[Self], weights: &[T], mean: &Self) -> Option<Vec<Vec<T>>> {
+        let total = check_weights(motors, weights)?;
+        let inverse = Motor::from_even(mean).normalized().reverse();
+        let tangents: Vec<[T; 6]> = motors.iter().map(|m| {
+            let b = (&inverse * &Motor::from_even(m)).log();
+            MOTOR_TANGENT.map(|bits| b.coefficient(bits).clone())
+        }).collect();
+        Some(covariance(&tangents, weights, &total))
+    }
+}
+
+/// The sum of the weights, `None` unless there is one for each element, they are positive and there is at least one.
+fn check_weights<X, T: Real>(elements: &[X], weights: &[T]) -> Option<T> {
+    if elements.is_empty() || elements.len() != weights.len() || weights.iter().any(|w| *w <= T::zero()) {
+        return None;
+    }
+    Some(weights.iter().fold(T::zero(), |s, w| s + w.clone()))
+}
+
+/// `log(mean~ rotor)`, taking the shorter way round.
+fn rotor_tangent<T: Real>(mean: &Vga<T, 3>, rotor: &Vga<T, 3>) -> Option<Vga<T, 3>> {
+    let mut step = mean.reverse().geometric_product(rotor);
+    if step.scalar_part() < T::zero() {
+        step = -step;
+    }
+    Some(step.log()?.grade(2))
+}
+
+fn covariance<T: Real, const N: usize>(tangents: &[[T; N]], weights: &[T], total: &T) -> Vec<Vec<T>> {
+    (0..N).map(|i| (0..N).map(|j| {
+        tangents.iter().zip(weights).fold(T::zero(), |s, (b, w)| s + w.clone() * b[i].clone() * b[j].clone()) / total.clone()
+    }).collect()).collect()
+}
@@ -0,0 +1,122 @@
+use crate::{Motor, Pga, Vga};
+use crate::multivector::Zero;
+use crate::tests::close;
+
+type V = Vga<f64, 3>;
+type P = Pga<f64, 3>;
+
+/// Equal up to sign, as `x` and `-x` are the same rotation or motion.
+fn same<const N: usize>(lhs: &[f64], rhs: &[f64]) -> bool {
+    let sign = if lhs.iter().zip(rhs).map(|(a, b)| a * b).sum::<f64>() < 0.0 { -1.0 } else { 1.0 };
+    lhs[..N].iter().zip(&rhs[..N]).all(|(a, b)| close(sign * a, *b))
+}
+
+fn same_rotor(lhs: &V, rhs: &V) -> bool {
+    same::<8>(&(0..8).map(|i| lhs[i]).collect::<Vec<_>>(), &(0..8).map(|i| rhs[i]).collect::<Vec<_>>())
+}
+
+fn same_motor(lhs: &P, rhs: &P) -> bool {
+    same::<16>(&(0..16).map(|i| lhs[i]).collect::<Vec<_>>(), &(0..16).map(|i| rhs[i]).collect::<Vec<_>>())
+}
+
+fn rotors() -> Vec<V> {
+    vec![
+        V::from_axis_angle([1.0, 0.2, 0.0], 0.4),
+        V::from_axis_angle([0.0, 1.0, 0.3], -0.3),
+        V::from_axis_angle([0.5, -0.5, 1.0], 0.6),
+        V::from_axis_angle([1.0, 1.0, 1.0], 0.2),
+    ]
+}
+
+fn motors() -> Vec<P> {
+    vec![
+        P::from(Motor::from_translation(1.0, 0.0, 0.5) * Motor::from_axis_angle([1.0, 0.2, 0.0], 0.4)),
+        P::from(Motor::from_translation(0.0, 2.0, -0.5) * Motor::from_axis_angle([0.0, 1.0, 0.3], -0.3)),
+        P::from(Motor::from_translation(-1.0, 0.5, 0.0) * Motor::from_axis_angle([0.5, -0.5, 1.0], 0.6)),
+    ]
+}
+
+#[test]
+fn rotor_means() {
+    // Rotations about one axis average their angles.
+    let about_z = |angle: f64| V::from_axis_angle([0.0, 0.0, 1.0], angle);
+    let turns = [about_z(0.2), about_z(1.0), about_z(-0.4)];
+    assert!(same_rotor(&V::geodesic_mean(&turns).unwrap(), &about_z(0.8 / 3.0)));
+    assert!(same_rotor(&V::weighted_geodesic_mean(&turns, &[1.0, 2.0, 1.0]).unwrap(), &about_z(1.8 / 4.0)));
+    let symmetric = [about_z(0.1), about_z(0.9)];
+    assert!(same_rotor(&V::chordal_mean(&symmetric).unwrap(), &about_z(0.5)));
+    assert!(same_rotor(&V::weighted_chordal_mean(&[about_z(0.3), about_z(0.3)], &[1.0, 5.0]).unwrap(), &about_z(0.3)));
+
+    // Signs do not matter, and the geodesic mean moves with the rotors.
+    let rotors = rotors();
+    let mean = V::geodesic_mean(&rotors).unwrap();
+    let flipped: Vec<V> = rotors.iter().enumerate().map(|(i, r)| if i % 2 == 0 { -r.clone() } else { r.clone() }).collect();
+    assert!(same_rotor(&V::geodesic_mean(&flipped).unwrap(), &mean));
+    assert!(same_rotor(&V::chordal_mean(&flipped).unwrap(), &V::chordal_mean(&rotors).unwrap()));
+    let q = V::from_axis_angle([2.0, -1.0, 0.5], 1.7);
+    let moved: Vec<V> = rotors.iter().map(|r| q.geometric_product(r)).collect();
+    assert!(same_rotor(&V::geodesic_mean(&moved).unwrap(), &q.geometric_product(&mean)));
+
+    // At the geodesic mean, the logarithms balance.
+    let sum = rotors.iter().fold(V::zero(), |s, r| s + mean.reverse().geometric_product(r).log().unwrap().grade(2));
+    assert!((0..8).all(|i| close(sum[i], 0.0)));
+
+    assert!(V::chordal_mean(&[]).is_none());
+    assert!(V::weighted_geodesic_mean(&rotors, &[1.0, 1.0, 0.0, 1.0]).is_none());
+    assert!(V::weighted_chordal_mean(&rotors, &[1.0, 1.0]).is_none());
+}
+
+#[test]
+fn motor_means() {
+    let translations = [
+        P::from(Motor::from_translation(1.0, 0.0, 0.0)),
+        P::from(Motor::from_translation(0.0, 3.0, 0.0)),
+        P::from(Motor::from_translation(2.0, 0.0, -3.0)),
+    ];
+    let expected = P::from(Motor::from_translation(1.0, 1.0, -1.0));
+    assert!(same_motor(&P::chordal_mean(&translations).unwrap(), &expected));
+    assert!(same_motor(&P::geodesic_mean(&translations).unwrap(), &expected));
+    let expected = P::from(Motor::from_translation(1.0, 0.75, -0.75));
+    assert!(same_motor(&P::weighted_geodesic_mean(&translations, &[2.0, 1.0, 1.0]).unwrap(), &expected));
+
+    // The geodesic mean moves with the motors, on either side.
+    let motors = motors();
+    let mean = P::geodesic_mean(&motors).unwrap();
+    let q = P::from(Motor::from_translation(0.3, -1.0, 2.0) * Motor::from_axis_angle([0.0, 1.0, 1.0], 1.2));
+    let left: Vec<P> = motors.iter().map(|m| q.geometric_product(m)).collect();
+    assert!(same_motor(&P::geodesic_mean(&left).unwrap(), &q.geometric_product(&mean)));
+    let right: Vec<P> = motors.iter().map(|m| m.geometric_product(&q)).collect();
+    assert!(same_motor(&P::geodesic_mean(&right).unwrap(), &mean.geometric_product(&q)));
+
+    // The chordal mean is near the geodesic mean for close motors.
+    let chordal = P::chordal_mean(&motors).unwrap();
+    assert!((0..16).all(|i| (chordal[i] - mean[i]).abs() < 1e-2));
+    let flipped = [motors[0].clone(), -motors[1].clone(), motors[2].clone()];
+    assert!(same_motor(&P::chordal_mean(&flipped).unwrap(), &chordal));
+    assert!(P::geodesic_mean(&[]).is_none());
+}
+
+#[test]
+fn covariance() {
+    // Rotors `mean exp(+-b)` spread along `b`.
+    let mean = V::from_axis_angle([1.0, 2.0, 0.0], 0.8);
+    let mut b = V::zero();
+    *b.coefficient_mut(0b011) = 0.1;
+    *b.coefficient_mut(0b110) = -0.2;
+    let rotors = [mean.geometric_product(&b.exp()), mean.geometric_product(&(-b.clone()).exp())];
+    let found = V::covariance(&rotors, &[1.0, 1.0], &V::geodesic_mean(&rotors).unwrap()).unwrap();
+    let coefficients = [0.1, 0.0, -0.2];
+    assert!((0..3).all(|i| (0..3).all(|j| close(found[i][j], coefficients[i] * coefficients[j]))));
+    let weighted = V::covariance(&[mean.clone(), rotors[0].clone()], &[3.0, 1.0], &mean).unwrap();
+    assert!((0..3).all(|i| (0..3).all(|j| close(weighted[i][j], coefficients[i] * coefficients[j] / 4.0))));
+
+    // Motors spread along a screw.
+    let mean = Motor::from_even(&motors()[0]);
+    let line = P::point(1.0, 0.0, 0.0).line_through(&P::point(1.0, 1.0, 2.0)).scale(&0.1);
+    let b = line + P::translator(0.0, 0.2, 0.0).grade(2);
+    let motors = [P::from(mean * Motor::exp(&b)), P::from(mean * Motor::exp(&-b.clone()))];
+    let found = P::covariance(&motors, &[1.0, 1.0], &P::geodesic_mean(&motors).unwrap()).unwrap();
+    let coefficients = [0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100].map(|bits| *b.coefficient(bits));
+    assert!((0..6).all(|i| (0..6).all(|j| close(found[i][j], coefficients[i] * coefficients[j]))));
+    assert!(P::covariance(&motors, &[1.0], &P::from(mean)).is_none());
+}
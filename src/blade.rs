@@ -0,0 +1,146 @@
+use crate::multivector::{Clifford, Multivector, One, Real};
+
+#[cfg(test)]
+mod tests;
+
+
+/// Blades, the outer products of vectors, as the subspaces they span.
+///
+/// Following Fontijne and Dorst, factorization and the meet and join measure and project with the Euclidean
+/// metric on the coefficients whatever the metric of `C`, as the subspaces do not depend on it. Factors are
+/// orthonormal in that metric, and so in the metric of `C` for Euclidean algebras, and small parts are
+/// judged relative to `sqrt(epsilon)` times the size of the blades.
+impl<T, const C: Clifford> Multivector<T, C> where
+T: Real,
+[(); C.size()]: Sized,
+{
+    pub(crate) fn euclidean_norm(&self) -> T {
+        (0..C.size()).fold(T::zero(), |n, i| n + self[i].clone() * self[i].clone()).sqrt()
+    }
+
+    fn euclidean_left_contraction(&self, other: &Self) -> Self {
+        self.euclidean_product(other, |i, j| i & !j == 0)
+    }
+
+    fn euclidean_inverse(&self) -> Self {
+        let n = self.euclidean_norm();
+        self.reverse().scale(&(T::one() / (n.clone() * n)))
+    }
+
+    /// The projection `(self . blade) . blade^-1` of a vector onto a blade.
+    fn euclidean_projection(&self, blade: &Self) -> Self {
+        self.euclidean_left_contraction(blade).euclidean_left_contraction(&blade.euclidean_inverse())
+    }
+
+    fn euclidean_normalized(&self) -> Self {
+        self.scale(&(T::one() / self.euclidean_norm()))
+    }
+
+    /// The grade of a homogeneous element up to rounding, `None` for zero and for mixed grades.
+    pub fn blade_grade(&self) -> Option<usize> {
+        let norm = self.euclidean_norm();
+        if norm == T::zero() {
+            return None;
+        }
+        let norms: Vec<T> = (0..=C.dim()).map(|k| self.grade(k).euclidean_norm()).collect();
+        let k = (1..=C.dim()).fold(0, |k, j| if norms[j] > norms[k] { j } else { k });
+        let rest = (0..=C.dim()).filter(|&j| j != k).fold(T::zero(), |r, j| r + norms[j].clone() * norms[j].clone());
+        if rest.sqrt() <= T::epsilon().sqrt() * norm {
+            Some(k)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `self` is the outer product of vectors up to rounding, the scalars included.
+    pub fn is_blade(&self) -> bool {
+        self.factorize().is_some()
+    }
+
+    /// A scale and unit vectors, orthogonal in the Euclidean metric, whose outer product times the scale is
+    /// `self`, or `None` if it is not a blade.
+    ///
+    /// The vectors of the basis blade with the largest coefficient are projected onto the blade in turn, and
+    /// each projection split off before the next.
+    pub fn factorize(&self) -> Option<(T, Vec<Self>)> {
+        let k = self.blade_grade()?;
+        let x = self.grade(k);
+        if k == 0 {
+            return Some((x.scalar_part(), Vec::new()));
+        }
+        let largest = (0..C.size())
+            .filter(|&bits| Clifford::grade(bits) == k)
+            .fold(None, |m: Option<usize>, bits| match m {
+                Some(m) if x.coefficient(m).abs() >= x.coefficient(bits).abs() => Some(m),
+                _ => Some(bits),
+            })?;
+        let mut blade = x.euclidean_normalized();
+        let mut factors = Vec::new();
+        for i in (0..C.dim()).filter(|i| largest & 1 << i != 0).take(k - 1) {
+            let f = Self::basis(1 << i).euclidean_projection(&blade).euclidean_normalized();
+            blade = f.euclidean_left_contraction(&blade);
+            factors.push(f);
+        }
+        factors.push(blade.grade(1).euclidean_normalized());
+
+        let w = factors.iter().fold(Self::one(), |w, f| w.outer_product(f));
+        let dot = |u: &Self, v: &Self| (0..C.size()).fold(T::zero(), |s, i| s + u[i].clone() * v[i].clone());
+        let scale = dot(&x, &w) / dot(&w, &w);
+        let error = (x.clone() - w.scale(&scale)).euclidean_norm();
+        if error <= T::epsilon().sqrt() * x.euclidean_norm() {
+            Some((scale, factors))
+        } else {
+            None
+        }
+    }
+
+    /// The meet and join of two blades, the blades of the intersection and the sum of the subspaces they span,
+    /// up to scale; `None` if either is not a blade.
+    ///
+    /// Unlike the regressive product this does not need the blades in general position. Following Fontijne
+    /// and Dorst, the grades of both follow from the grade of the highest part of the Euclidean geometric
+    /// product, and the complement of that part is spanned by vectors of the meet and vectors orthogonal to
+    /// the join, which are collected by projecting its factors onto the blade of lower grade until one of
+    /// them is complete. The join is then `self ^ (meet^-1 . other)`.
+    pub fn meet_join(&self, other: &Self) -> Option<(Self, Self)> {
+        if !self.is_blade() || !other.is_blade() {
+            return None;
+        }
+        let (a, b) = if self.blade_grade()? <= other.blade_grade()? { (self, other) } else { (other, self) };
+        let (a, b) = (a.euclidean_normalized(), b.euclidean_normalized());
+        let (ka, kb) = (a.blade_grade()?, b.blade_grade()?);
+        let tolerance = T::epsilon().sqrt();
+
+        let product = a.euclidean_product(&b, |_, _| true);
+        let d = (0..=C.dim()).rev().find(|&k| product.grade(k).euclidean_norm() > tolerance.clone())?;
+        let (meet_grade, join_grade) = ((ka + kb - d) / 2, (ka + kb + d) / 2);
+        let pseudoscalar = Self::basis(C.pseudoscalar_bits());
+        let complement = product.grade(d).euclidean_left_contraction(&pseudoscalar.reverse());
+        let (_, factors) = complement.factorize()?;
+
+        let (mut meet, mut meet_k) = (Self::one(), 0);
+        let (mut join, mut join_k) = (pseudoscalar, C.dim());
+        for s in factors {
+            if meet_k == meet_grade || join_k == join_grade {
+                break;
+            }
+            let p = s.euclidean_projection(&a);
+            let m = meet.outer_product(&p);
+            if m.euclidean_norm() > tolerance {
+                meet = m.euclidean_normalized();
+                meet_k += 1;
+            }
+            let j = (s - p).euclidean_left_contraction(&join);
+            if j.euclidean_norm() > tolerance {
+                join = j.euclidean_normalized();
+                join_k -= 1;
+            }
+        }
+        if meet_k != meet_grade {
+            meet = b.euclidean_left_contraction(&join.euclidean_inverse()).euclidean_left_contraction(&a);
+        }
+        let meet = meet.euclidean_normalized();
+        let join = a.outer_product(&meet.euclidean_inverse().euclidean_left_contraction(&b));
+        Some((meet, join))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Pga, Vga};
+use crate::multivector::Zero;
+use crate::tests::{close, same};
+
+type V = Vga<f64, 5>;
+
+fn vector(x: [f64; 5]) -> V {
+    V::from_vector(x)
+}
+
+/// Whether two blades span the same subspace, with `lhs ^ v == 0` exactly when `rhs ^ v == 0`.
+fn same_subspace(lhs: &V, rhs: &V) -> bool {
+    let (k, (s, factors)) = (lhs.blade_grade().unwrap(), rhs.factorize().unwrap());
+    k == factors.len() && s != 0.0 && factors.iter().all(|f| same(&lhs.outer_product(f), &V::zero()))
+}
+
+#[test]
+fn factorization() {
+    let (a, b, c) = (vector([1.0, 2.0, 0.0, -1.0, 0.5]), vector([0.0, 1.0, 3.0, 1.0, 0.0]), vector([2.0, 0.0, 1.0, 0.0, -1.0]));
+    let x = a.outer_product(&b).outer_product(&c);
+    assert_eq!(x.blade_grade(), Some(3));
+    let (scale, factors) = x.factorize().unwrap();
+    assert_eq!(factors.len(), 3);
+    let y = factors.iter().fold(V::from_scalar(scale), |y, f| y.outer_product(f));
+    assert!(same(&x, &y));
+    for (i, f) in factors.iter().enumerate() {
+        assert!(close(f.inner_product(f), 1.0));
+        assert!(factors[..i].iter().all(|g| close(f.inner_product(g), 0.0)));
+    }
+
+    assert!(V::from_scalar(2.0).is_blade());
+    assert!(!(V::basis(0b00011) + V::basis(0b01100)).is_blade());
+    assert!(!(V::basis(0b00011) + V::basis(0b00001)).is_blade());
+    assert_eq!((V::basis(0b00011) + V::basis(0b00001)).blade_grade(), None);
+}
+
+#[test]
+fn factorization_in_complementary_layout() {
+    // `e31` is laid out in place of `e13`, which the Euclidean products have to respect.
+    type M = crate::Multivector<f64, { crate::Clifford::new(3, 0, 0).lexicographic().complementary() }>;
+    let x = M::from_vector([1.0, 2.0, 0.0]).outer_product(&M::from_vector([0.0, 1.0, 3.0]));
+    let (scale, factors) = x.factorize().unwrap();
+    assert!(same(&factors.iter().fold(M::from_scalar(scale), |y, f| y.outer_product(f)), &x));
+}
+
+#[test]
+fn meet_and_join() {
+    let (a, b, c, d) = (vector([1.0, 2.0, 0.0, -1.0, 0.5]), vector([0.0, 1.0, 3.0, 1.0, 0.0]), vector([2.0, 0.0, 1.0, 0.0, -1.0]), vector([0.0, 0.0, 1.0, 1.0, 1.0]));
+
+    // Two planes sharing a line.
+    let (meet, join) = a.outer_product(&b).meet_join(&a.outer_product(&c)).unwrap();
+    assert!(same_subspace(&meet, &a));
+    assert!(same_subspace(&join, &a.outer_product(&b).outer_product(&c)));
+
+    // Not in general position: a plane and a volume containing it, or the same line twice.
+    let (meet, join) = b.outer_product(&c).meet_join(&a.outer_product(&b).outer_product(&c)).unwrap();
+    assert!(same_subspace(&meet, &b.outer_product(&c)));
+    assert!(same_subspace(&join, &a.outer_product(&b).outer_product(&c)));
+    let (meet, join) = d.meet_join(&d.scale(&-3.0)).unwrap();
+    assert!(same_subspace(&meet, &d) && same_subspace(&join, &d));
+
+    // Disjoint subspaces meet in a scalar.
+    let (meet, join) = a.outer_product(&b).meet_join(&c.outer_product(&d)).unwrap();
+    assert_eq!(meet.blade_grade(), Some(0));
+    assert!(same_subspace(&join, &a.outer_product(&b).outer_product(&c).outer_product(&d)));
+}
+
+#[test]
+fn meet_in_degenerate_metric() {
+    // Two planes of 3D PGA as 3-blades of points, meeting in the line through two shared points.
+    type P = Pga<f64, 3>;
+    let point = |x: [f64; 3]| P::from_vector([x[0], x[1], x[2], 1.0]);
+    let (p, q, r, s) = (point([0.0, 0.0, 0.0]), point([1.0, 0.0, 0.0]), point([0.0, 1.0, 0.0]), point([0.0, 0.0, 1.0]));
+    let (meet, join) = p.outer_product(&q).outer_product(&r).meet_join(&p.outer_product(&q).outer_product(&s)).unwrap();
+    let line = p.outer_product(&q);
+    assert_eq!(meet.blade_grade(), Some(2));
+    assert!(same(&meet.outer_product(&p), &P::zero()) && same(&meet.outer_product(&q), &P::zero()));
+    assert_eq!(join.blade_grade(), Some(4));
+    assert!(line.is_blade());
+}
@@ -0,0 +1,205 @@
+use crate::Cga;
+use crate::multivector::Real;
+use core::cmp::Ordering;
+
+#[cfg(test)]
+mod tests;
+
+
+const E4: usize = 0b01000;
+const E5: usize = 0b10000;
+const I: usize = 0b11111;
+
+/// Round and flat objects of the conformal model of space, `Cl(4, 1)` with `e5 * e5 == -1`.
+///
+/// Points are the null vectors `x + n_o + x^2 / 2 n_inf`, with the origin `n_o = (e5 - e4) / 2` and the point
+/// at infinity `n_inf = e4 + e5`, so that `p . q` is `-|p - q|^2 / 2` for unit points.
+///
+/// Each object has two representations: in OPNS form, as the outer product of the points spanning it, its
+/// points `x` satisfy `x ^ object == 0`; in IPNS form, the dual of the former, they satisfy `x . object == 0`.
+/// Constructors named `dual_*` give the IPNS form, as in the conformal geometric algebra literature.
+impl<T> Cga<T, 4> where
+T: Real,
+{
+    pub fn origin() -> Self {
+        (Self::basis(E5) - Self::basis(E4)).scale(&T::from_f64(0.5))
+    }
+
+    pub fn infinity() -> Self {
+        Self::basis(E5) + Self::basis(E4)
+    }
+
+    pub fn point(x: T, y: T, z: T) -> Self {
+        let square = x.clone() * x.clone() + y.clone() * y.clone() + z.clone() * z.clone();
+        Self::from_vector([x, y, z]) + Self::origin() + Self::infinity().scale(&(square * T::from_f64(0.5)))
+    }
+
+    /// The `(x, y, z)` coordinates of a point or flat point, `None` for points at infinity.
+    pub fn to_point(&self) -> Option<(T, T, T)> {
+        let w = self.weight();
+        if w == T::zero() {
+            return None;
+        }
+        let [x, y, z] = [0b001, 0b010, 0b100].map(|e| self.coefficient(e).clone() / w.clone());
+        Some((x, y, z))
+    }
+
+    /// The scale `-n_inf . self` of a point, one for those made by `point`.
+    fn weight(&self) -> T {
+        Self::infinity().inner_product(self).neg()
+    }
+
+    fn from_array(x: [T; 3]) -> Self {
+        let [x, y, z] = x;
+        Self::point(x, y, z)
+    }
+
+    /// The IPNS form of an object given in OPNS form, `self * I^-1`.
+    pub fn to_ipns(&self) -> Self {
+        -self.geometric_product(&Self::basis(I))
+    }
+
+    /// The OPNS form of an object given in IPNS form, `self * I`.
+    pub fn to_opns(&self) -> Self {
+        self.geometric_product(&Self::basis(I))
+    }
+
+    /// The sphere with the given center and radius, an imaginary sphere for negative `radius_squared`.
+    pub fn dual_sphere(center: [T; 3], radius_squared: T) -> Self {
+        Self::from_array(center) - Self::infinity().scale(&(radius_squared * T::from_f64(0.5)))
+    }
+
+    pub fn sphere(center: [T; 3], radius_squared: T) -> Self {
+        Self::dual_sphere(center, radius_squared).to_opns()
+    }
+
+    /// The plane `normal . x == distance`.
+    pub fn dual_plane(normal: [T; 3], distance: T) -> Self {
+        Self::from_vector(normal) + Self::infinity().scale(&distance)
+    }
+
+    pub fn plane(normal: [T; 3], distance: T) -> Self {
+        Self::dual_plane(normal, distance).to_opns()
+    }
+
+    /// The circle with the given center and radius in the plane through the center with the given normal.
+    pub fn dual_circle(center: [T; 3], normal: [T; 3], radius_squared: T) -> Self {
+        let distance = (0..3).fold(T::zero(), |d, i| d + normal[i].clone() * center[i].clone());
+        Self::dual_sphere(center, radius_squared).outer_product(&Self::dual_plane(normal, distance))
+    }
+
+    pub fn circle(center: [T; 3], normal: [T; 3], radius_squared: T) -> Self {
+        Self::dual_circle(center, normal, radius_squared).to_opns()
+    }
+
+    /// The pair of points at `center - radius * direction` and `center + radius * direction` for a unit direction.
+    pub fn point_pair(center: [T; 3], direction: [T; 3], radius: T) -> Self {
+        let at = |sign: T| Self::from_array(core::array::from_fn(|i| {
+            center[i].clone() + sign.clone() * radius.clone() * direction[i].clone()
+        }));
+        at(T::one().neg()).outer_product(&at(T::one()))
+    }
+
+    pub fn dual_point_pair(center: [T; 3], direction: [T; 3], radius: T) -> Self {
+        Self::point_pair(center, direction, radius).to_ipns()
+    }
+
+    /// The point pair of two points in OPNS form.
+    pub fn pair_through(&self, other: &Self) -> Self {
+        self.outer_product(other)
+    }
+
+    /// The circle through three points in OPNS form.
+    pub fn circle_through(&self, b: &Self, c: &Self) -> Self {
+        self.outer_product(b).outer_product(c)
+    }
+
+    /// The sphere through four points in OPNS form.
+    pub fn sphere_through(&self, b: &Self, c: &Self, d: &Self) -> Self {
+        self.outer_product(b).outer_product(c).outer_product(d)
+    }
+
+    /// The line through two points in OPNS form.
+    pub fn line_through(&self, other: &Self) -> Self {
+        self.outer_product(other).outer_product(&Self::infinity())
+    }
+
+    /// The plane through three points in OPNS form.
+    pub fn plane_through(&self, b: &Self, c: &Self) -> Self {
+        self.outer_product(b).outer_product(c).outer_product(&Self::infinity())
+    }
+
+    /// The intersection of two objects in IPNS form, itself in IPNS form, e.g. the circle where two spheres
+    /// or a sphere and a plane meet or the point pair where a line pierces a sphere.
+    ///
+    /// Objects that miss each other meet in imaginary rounds, with negative `radius_squared`.
+    pub fn meet(&self, other: &Self) -> Self {
+        self.outer_product(other)
+    }
+
+    /// `n_inf . self`, the flat part of a round in OPNS form.
+    fn carrier(&self) -> Self {
+        Self::infinity().left_contraction(self)
+    }
+
+    /// The center of a sphere, circle or point pair in OPNS form.
+    pub fn center(&self) -> Option<(T, T, T)> {
+        let i = Self::infinity();
+        self.geometric_product(&i).geometric_product(self).grade(1).to_point()
+    }
+
+    /// The squared radius of a sphere, circle or point pair in OPNS form, negative for imaginary rounds.
+    pub fn radius_squared(&self) -> T {
+        let carrier = self.carrier();
+        self.geometric_product(&self.involute()).scalar_part() / carrier.geometric_product(&carrier).scalar_part()
+    }
+
+    /// The radius of a real sphere, circle or point pair in OPNS form, `None` for imaginary ones.
+    pub fn radius(&self) -> Option<T> {
+        let r = self.radius_squared();
+        if r < T::zero() {
+            None
+        } else {
+            Some(r.sqrt())
+        }
+    }
+
+    /// The two points of a point pair in OPNS form, `None` for imaginary pairs, in the order of `pair_through`.
+    pub fn points(&self) -> Option<(Self, Self)> {
+        let square = self.geometric_product(self).scalar_part();
+        if square < T::zero() {
+            return None;
+        }
+        let root = square.sqrt();
+        let carrier = self.carrier();
+        let at = |x: Self| {
+            let x = x.geometric_product(&carrier).grade(1);
+            let w = x.weight();
+            x.scale(&(T::one() / w))
+        };
+        Some((at(self.clone() - root.clone()), at(self.clone() + root)))
+    }
+
+    /// Which side of a sphere or plane in IPNS form a point lies on: `Greater` inside a sphere or on the side
+    /// of a plane its normal points to, `Less` outside or behind it and `Equal` on it, up to rounding.
+    ///
+    /// A plane is a sphere through infinity whose inside lies along its normal, and imaginary spheres have
+    /// every point outside.
+    pub fn side(&self, point: &Self) -> Ordering {
+        let w = self.weight();
+        let s = if w == T::zero() {
+            self.scale(&(T::one() / self.inner_product(self).sqrt()))
+        } else {
+            self.scale(&(T::one() / w))
+        };
+        let v = point.scale(&(T::one() / point.weight())).inner_product(&s);
+        let tolerance = T::epsilon().sqrt() * (T::one() + s.inner_product(&s).abs());
+        if v.abs() <= tolerance {
+            Ordering::Equal
+        } else if v > T::zero() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::Cga;
+use crate::tests::close;
+use core::cmp::Ordering;
+
+type C = Cga<f64, 4>;
+
+fn at(x: Option<(f64, f64, f64)>, y: [f64; 3]) -> bool {
+    let (a, b, c) = x.unwrap();
+    close(a, y[0]) && close(b, y[1]) && close(c, y[2])
+}
+
+#[test]
+fn points() {
+    let p = C::point(1.0, -2.0, 0.5);
+    let q = C::point(-1.0, 0.0, 2.5);
+    assert!(close(p.inner_product(&p), 0.0));
+    assert!(close(p.inner_product(&q), -0.5 * (4.0 + 4.0 + 4.0)));
+    assert!(at(p.scale(&3.0).to_point(), [1.0, -2.0, 0.5]));
+    assert!(C::infinity().to_point().is_none());
+    assert!(at(C::origin().to_point(), [0.0, 0.0, 0.0]));
+}
+
+#[test]
+fn rounds() {
+    let sphere = C::sphere([1.0, 2.0, -1.0], 4.0);
+    assert!(at(sphere.center(), [1.0, 2.0, -1.0]));
+    assert!(close(sphere.radius_squared(), 4.0));
+    assert!(close(C::sphere([0.0, 0.0, 1.0], -9.0).radius_squared(), -9.0));
+    assert!(C::sphere([0.0, 0.0, 1.0], -9.0).radius().is_none());
+
+    let circle = C::circle([0.5, 0.0, 3.0], [0.0, 1.0, 1.0], 2.25);
+    assert!(at(circle.center(), [0.5, 0.0, 3.0]));
+    assert!(close(circle.radius().unwrap(), 1.5));
+
+    let pair = C::point_pair([0.0, 1.0, 1.0], [0.6, 0.0, 0.8], 2.0);
+    assert!(at(pair.center(), [0.0, 1.0, 1.0]));
+    assert!(close(pair.radius().unwrap(), 2.0));
+    let (a, b) = pair.points().unwrap();
+    assert!(at(a.to_point(), [-1.2, 1.0, -0.6]));
+    assert!(at(b.to_point(), [1.2, 1.0, 2.6]));
+
+    for x in [sphere, circle, pair] {
+        assert!(close(x.to_ipns().to_opns().radius_squared(), x.radius_squared()));
+    }
+}
+
+#[test]
+fn through_points() {
+    let p = [C::point(1.0, 0.0, 0.0), C::point(0.0, 1.0, 0.0), C::point(-1.0, 0.0, 0.0), C::point(0.0, 0.0, 1.0)];
+    let sphere = p[0].sphere_through(&p[1], &p[2], &p[3]);
+    assert!(at(sphere.center(), [0.0, 0.0, 0.0]));
+    assert!(close(sphere.radius_squared(), 1.0));
+    let circle = p[0].circle_through(&p[1], &p[2]);
+    assert!(at(circle.center(), [0.0, 0.0, 0.0]));
+    assert!(close(circle.radius_squared(), 1.0));
+    let pair = p[0].pair_through(&p[3]);
+    assert!(at(pair.center(), [0.5, 0.0, 0.5]));
+    assert!(close(pair.radius_squared(), 0.5));
+    let (a, b) = pair.points().unwrap();
+    assert!(at(a.to_point(), [1.0, 0.0, 0.0]));
+    assert!(at(b.to_point(), [0.0, 0.0, 1.0]));
+
+    for x in &p {
+        assert!(close(sphere.to_ipns().inner_product(x), 0.0));
+        assert_eq!(sphere.to_ipns().side(x), Ordering::Equal);
+    }
+    let plane = p[0].plane_through(&p[1], &p[3]).to_ipns();
+    assert_eq!(plane.side(&C::point(1.0, 1.0, 1.0)), plane.side(&C::point(2.0, 0.0, 0.0)));
+    assert_ne!(plane.side(&C::origin()), plane.side(&C::point(1.0, 1.0, 1.0)));
+}
+
+#[test]
+fn intersections() {
+    let sphere = C::dual_sphere([0.0, 0.0, 0.0], 25.0);
+    let plane = C::dual_plane([0.0, 0.0, 1.0], 3.0);
+    let circle = sphere.meet(&plane).to_opns();
+    assert!(at(circle.center(), [0.0, 0.0, 3.0]));
+    assert!(close(circle.radius_squared(), 16.0));
+    let missed = sphere.meet(&C::dual_plane([0.0, 0.0, 1.0], 6.0)).to_opns();
+    assert!(at(missed.center(), [0.0, 0.0, 6.0]));
+    assert!(close(missed.radius_squared(), -11.0));
+
+    let other = C::dual_sphere([6.0, 0.0, 0.0], 25.0);
+    let circle = sphere.meet(&other).to_opns();
+    assert!(at(circle.center(), [3.0, 0.0, 0.0]));
+    assert!(close(circle.radius_squared(), 16.0));
+
+    let line = C::point(-10.0, 0.0, 4.0).line_through(&C::point(10.0, 0.0, 4.0)).to_ipns();
+    let pair = sphere.meet(&line).to_opns();
+    assert!(at(pair.center(), [0.0, 0.0, 4.0]));
+    assert!(close(pair.radius_squared(), 9.0));
+    let (a, b) = pair.points().unwrap();
+    let (a, b) = (a.to_point().unwrap(), b.to_point().unwrap());
+    assert!(close(a.0.abs(), 3.0) && close(a.0 + b.0, 0.0));
+    assert!(close(a.2, 4.0) && close(b.2, 4.0));
+    let line = C::point(-10.0, 0.0, 7.0).line_through(&C::point(10.0, 0.0, 7.0)).to_ipns();
+    assert!(sphere.meet(&line).to_opns().points().is_none());
+}
+
+#[test]
+fn sides() {
+    let sphere = C::dual_sphere([1.0, 1.0, 1.0], 4.0);
+    assert_eq!(sphere.side(&C::point(1.0, 1.0, 2.0)), Ordering::Greater);
+    assert_eq!(sphere.side(&C::point(1.0, 1.0, 3.0)), Ordering::Equal);
+    assert_eq!(sphere.side(&C::point(1.0, 1.0, 4.0).scale(&2.0)), Ordering::Less);
+    assert_eq!(sphere.scale(&-3.0).side(&C::point(1.0, 1.0, 2.0)), Ordering::Greater);
+    assert_eq!(C::dual_sphere([0.0; 3], -1.0).side(&C::origin()), Ordering::Less);
+
+    let plane = C::dual_plane([0.0, 2.0, 0.0], 2.0);
+    assert_eq!(plane.side(&C::point(5.0, 1.5, 0.0)), Ordering::Greater);
+    assert_eq!(plane.side(&C::point(5.0, 1.0, -3.0)), Ordering::Equal);
+    assert_eq!(plane.side(&C::point(5.0, 0.5, 0.0)), Ordering::Less);
+}
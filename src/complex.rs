@@ -0,0 +1,118 @@
+use crate::Complex;
+use crate::multivector::{Clifford, Multivector, Ring};
+use core::ops::{Div, Neg};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::Ratio;
+
+#[cfg(test)]
+mod tests;
+
+
+/// Complex conjugation of coefficients, as opposed to the Clifford conjugation of `Multivector::conjugate`.
+pub trait ComplexConjugate {
+    fn complex_conjugate(&self) -> Self;
+}
+
+macro_rules! impl_real_conjugate {
+    ($($t:ty)*) => {
+        $(
+            impl ComplexConjugate for $t {
+                fn complex_conjugate(&self) -> Self {
+                    self.clone()
+                }
+            }
+        )*
+    }
+}
+
+impl_real_conjugate!(f32 f64 i8 i16 i32 i64 i128 isize BigInt);
+
+impl<T> ComplexConjugate for Ratio<T> where
+T: Clone + Integer,
+{
+    fn complex_conjugate(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T> ComplexConjugate for Complex<T> where
+T: Clone + Neg<Output = T>,
+{
+    fn complex_conjugate(&self) -> Self {
+        Self::from([self.re(), self.im().neg()])
+    }
+}
+
+impl<T> Complex<T> where
+T: Clone,
+{
+    pub fn new(re: T, im: T) -> Self {
+        Self::from([re, im])
+    }
+
+    pub fn re(&self) -> T {
+        self[0].clone()
+    }
+
+    pub fn im(&self) -> T {
+        self[1].clone()
+    }
+}
+
+impl<T> Div for Complex<T> where
+T: Ring + Div<Output = T>,
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        let (a, b) = (self.re(), self.im());
+        let (c, d) = (other.re(), other.im());
+        let n = c.clone() * c.clone() + d.clone() * d.clone();
+        Self::new(
+            (a.clone() * c.clone() + b.clone() * d.clone()) / n.clone(),
+            (b * c - a * d) / n,
+        )
+    }
+}
+
+impl<T, const C: Clifford> Multivector<T, C> where
+[(); C.size()]: Sized,
+{
+    /// Complex conjugate every coefficient, leaving the basis blades untouched.
+    pub fn conjugate_coefficients(&self) -> Self where
+    T: ComplexConjugate,
+    {
+        self.map(ComplexConjugate::complex_conjugate)
+    }
+
+    /// The Hermitian adjoint, under which vectors squaring to `1` are Hermitian and those squaring to `-1`
+    /// anti-Hermitian, as for the Pauli and Dirac matrices.
+    ///
+    /// This is reversion composed with complex conjugation, with an extra sign per negative basis vector.
+    pub fn adjoint(&self) -> Self where
+    T: Clone + ComplexConjugate + Neg<Output = T>,
+    {
+        let mut x = self.conjugate_coefficients().reverse();
+        for i in 0..C.size() {
+            if (usize::count_ones(C.blade_to_bit(i) & C.negative_bits()) & 1) != 0 {
+                x[i] = x[i].clone().neg();
+            }
+        }
+        x
+    }
+
+    /// The sesquilinear scalar product `(self.adjoint() * other).scalar_part()`, antilinear in `self`.
+    ///
+    /// It is positive definite on non-degenerate algebras, with the basis blades orthonormal.
+    pub fn hermitian_product(&self, other: &Self) -> T where
+    T: Ring + ComplexConjugate,
+    {
+        let mut v = T::zero();
+        for i in 0..C.size() {
+            if C.blade_to_bit(i) & C.zero_bits() == 0 {
+                v += self[i].complex_conjugate() * other[i].clone();
+            }
+        }
+        v
+    }
+}
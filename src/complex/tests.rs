@@ -0,0 +1,61 @@
+use crate::{Complex, ComplexConjugate, Multivector, One, Sta, Vga};
+use crate::multivector::{Clifford, vga};
+
+const PAULI: Clifford = vga(3);
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+fn sigma(i: usize) -> Vga<Complex<f64>, 3> {
+    Multivector::basis(1 << i)
+}
+
+#[test]
+fn complex_arithmetic() {
+    assert_eq!(c(1.0, 2.0) * c(3.0, -1.0), c(5.0, 5.0));
+    assert_eq!(c(5.0, 5.0) / c(3.0, -1.0), c(1.0, 2.0));
+    assert_eq!(c(1.0, 2.0).complex_conjugate(), c(1.0, -2.0));
+}
+
+#[test]
+fn pauli_algebra() {
+    let pseudoscalar = &(&sigma(0) * &sigma(1)) * &sigma(2);
+    assert_eq!(&pseudoscalar * &pseudoscalar, -Multivector::one());
+    assert_eq!(&pseudoscalar * &sigma(0), &sigma(0) * &pseudoscalar);
+    assert_eq!(&sigma(0) * &sigma(1), &pseudoscalar * &sigma(2));
+}
+
+#[test]
+fn complex_and_clifford_conjugation_differ() {
+    let i = Multivector::<Complex<f64>, PAULI>::from_scalar(c(0.0, 1.0));
+    assert_eq!(i.conjugate_coefficients(), -i.clone());
+    assert_eq!(i.conjugate(), i.clone());
+    assert_eq!(sigma(0).conjugate_coefficients(), sigma(0));
+    assert_eq!(sigma(0).conjugate(), -sigma(0));
+}
+
+#[test]
+fn hermitian_adjoint() {
+    let i = Multivector::<Complex<f64>, PAULI>::from_scalar(c(0.0, 1.0));
+    let x = &i * &sigma(0) + sigma(1) + Multivector::from_scalar(c(2.0, -1.0));
+    let y = &sigma(2) * &sigma(0) + &i * &sigma(1);
+    assert_eq!(sigma(0).adjoint(), sigma(0));
+    assert_eq!((&i * &sigma(0)).adjoint(), -(&i * &sigma(0)));
+    assert_eq!((&x * &y).adjoint(), &y.adjoint() * &x.adjoint());
+
+    let gamma = |i: usize| Sta::<Complex<f64>>::basis(1 << i);
+    assert_eq!(gamma(0).adjoint(), gamma(0));
+    assert_eq!(gamma(1).adjoint(), -gamma(1));
+    let psi = &gamma(1) * &gamma(2) + gamma(3).scale(&c(0.0, 2.0)) + Multivector::from_scalar(c(1.0, 1.0));
+    assert_eq!(psi.adjoint(), &(&gamma(0) * &psi.reverse().conjugate_coefficients()) * &gamma(0));
+}
+
+#[test]
+fn sesquilinear_product() {
+    let i = Sta::<Complex<f64>>::from_scalar(c(0.0, 1.0));
+    let x = &i * &Multivector::basis(0b0011) + Multivector::basis(0b0100).scale(&c(3.0, 0.0));
+    assert_eq!(x.hermitian_product(&x), c(10.0, 0.0));
+    assert_eq!(x.hermitian_product(&(&i * &x)), c(0.0, 10.0));
+    assert_eq!((&i * &x).hermitian_product(&x), c(0.0, -10.0));
+}
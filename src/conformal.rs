@@ -0,0 +1,161 @@
+use crate::{Cga, Motor, Vga};
+use crate::multivector::{One, Real, Zero};
+
+#[cfg(test)]
+mod tests;
+
+
+/// Conformal transformations of space as versors of 3D CGA, acting on points, spheres, planes and the
+/// other objects of `cga3d` through `apply`.
+///
+/// Translators, rotors, dilators and transversors are even versors and compose by the geometric product,
+/// the one on the left acting last; inversions in spheres and reflections in planes are odd.
+impl<T> Cga<T, 4> where
+T: Real,
+{
+    /// The versor moving points by `t`, `1 - t n_inf / 2`.
+    pub fn translator(t: [T; 3]) -> Self {
+        Self::one() - Self::from_vector(t).geometric_product(&Self::infinity()).scale(&T::from_f64(0.5))
+    }
+
+    /// The versor of a rotor of 3D space, turning about the origin.
+    pub fn from_rotor(rotor: &Vga<T, 3>) -> Self {
+        let mut x = Self::zero();
+        for bits in 0..8 {
+            *x.coefficient_mut(bits) = rotor.coefficient(bits).clone();
+        }
+        x
+    }
+
+    /// The versor scaling space about the origin by `factor > 0`, `exp(ln(factor) n_o ^ n_inf / 2)`.
+    pub fn dilator(factor: T) -> Self {
+        let half = factor.ln() * T::from_f64(0.5);
+        let e = Self::origin().outer_product(&Self::infinity());
+        Self::from_scalar(half.cosh()) + e.scale(&half.sinh())
+    }
+
+    /// The versor of the transversion `x -> (x + x^2 b) / (1 + 2 b . x + b^2 x^2)`, `1 - b n_o`, which is
+    /// the translation by `b` conjugated by inversion in the unit sphere and fixes the origin.
+    pub fn transversor(b: [T; 3]) -> Self {
+        Self::one() - Self::from_vector(b).geometric_product(&Self::origin())
+    }
+
+    /// The odd versor of inversion in a sphere, `x -> center + radius^2 (x - center) / |x - center|^2`.
+    pub fn inversion(center: [T; 3], radius: T) -> Self {
+        Self::dual_sphere(center, radius.clone() * radius)
+    }
+
+    /// The odd versor of reflection in the plane `normal . x == distance`.
+    pub fn reflection(normal: [T; 3], distance: T) -> Self {
+        Self::dual_plane(normal, distance)
+    }
+
+    /// Apply a versor to any object, `self * x * self.inverse()` for even and
+    /// `self * x.involute() * self.inverse()` for odd versors, the outermorphism of its action on points. A
+    /// versor counts as odd when its even part is within rounding of zero, relative to its largest coefficient.
+    ///
+    /// Points come out scaled by the versor, e.g. by `|x - center|^2 / radius^2` for an inversion; `to_point`
+    /// reads them regardless.
+    pub fn apply(&self, x: &Self) -> Self {
+        let largest = |v: &Self| (0..32).fold(T::zero(), |m, i| if v[i].abs() > m { v[i].abs() } else { m });
+        let even = (0..=2).fold(Self::zero(), |e, k| e + self.grade(2 * k));
+        let odd = largest(&even) <= T::epsilon().sqrt() * largest(self);
+        let x = if odd { x.involute() } else { x.clone() };
+        self.geometric_product(&x).geometric_product(&self.inverse())
+    }
+
+    /// The bivector whose `exp` is an even unit versor, scaling `self` to unit norm first.
+    ///
+    /// A conformal transformation is the commuting product of at most two simple ones, such as a rotation
+    /// about a line combined with a dilation about a point of it, and its bivector the sum of theirs. Following
+    /// Roelfs and De Keninck, the two parts are split off the bivector part of the versor in closed form
+    /// and their logarithms taken separately. This is `None` when the two parts cannot be told apart, for
+    /// rotations by equal angles in two orthogonal planes, or for versors without a logarithm such as the
+    /// negative of a dilator.
+    pub fn versor_log(&self) -> Option<Self> {
+        let r = self.scale(&(T::one() / self.norm()));
+        let s = r.scalar_part();
+        let p = r.grade(2);
+        let square = p.geometric_product(&p);
+        let w = square.grade(4).scale(&T::from_f64(0.5));
+        let p0 = square.scalar_part();
+        let tolerance = T::epsilon().sqrt();
+        let negligible = |x: &Self| (0..32).all(|i| x[i].abs() <= tolerance);
+
+        // `w` is null when one part is a translation or transversion, so sizes are measured by coefficients.
+        // It also vanishes when one part is a half turn, leaving only the grade 4 part of `r` to show the other.
+        let (p1, p2) = if negligible(&w) {
+            if negligible(&r.grade(4)) {
+                return Self::simple_log(&(p + s));
+            }
+            (p, Self::zero())
+        } else {
+            let w2 = w.geometric_product(&w).scalar_part();
+            let discriminant = p0.clone() * p0.clone() - T::from_f64(4.0) * w2;
+            if discriminant <= tolerance.clone() * tolerance.clone() * (T::one() + p0.clone() * p0.clone()) {
+                return None;
+            }
+            let d = discriminant.sqrt();
+            let lambda = (p0 - d.clone()) * T::from_f64(0.5);
+            let p1 = (w.geometric_product(&p) - p.scale(&lambda)).grade(2).scale(&(T::one() / d));
+            (p1.clone(), p - p1)
+        };
+
+        // Split off the part with the larger `s + p_i` first, as that of the other vanishes when the other
+        // part turns by a half turn, and take the rest from `r`.
+        let (a, b) = (p1 + s.clone(), p2 + s);
+        let first = if a.norm() >= b.norm() { a } else { b };
+        let mut first = first.scale(&(T::one() / first.norm()));
+        if Self::hyperbolic(&first) && first.scalar_part() < T::zero() {
+            first = -first;
+        }
+        let mut second = first.reverse().geometric_product(&r);
+        second = second.clone() - second.grade(4);
+        if Self::hyperbolic(&second) && second.scalar_part() < T::zero() {
+            first = -first;
+            second = -second;
+        }
+        Some(Self::simple_log(&first)? + Self::simple_log(&second)?)
+    }
+
+    /// Whether the bivector part of a simple versor squares to a non-negative scalar, as for boosts and
+    /// translations, whose versors have no negative.
+    fn hyperbolic(x: &Self) -> bool {
+        let b = x.grade(2);
+        b.geometric_product(&b).scalar_part() >= T::zero()
+    }
+
+    /// The bivector logarithm of a unit versor `a + b` whose bivector `b` squares to a scalar up to rounding.
+    fn simple_log(x: &Self) -> Option<Self> {
+        let x = x.scale(&(T::one() / x.norm()));
+        let a = x.scalar_part();
+        let b = x.grade(2);
+        let square = b.geometric_product(&b).scalar_part();
+        if square.abs() <= T::epsilon() {
+            if a <= T::zero() {
+                return None;
+            }
+            return Some(b.scale(&(T::one() / a)));
+        }
+        if square < T::zero() {
+            let r = square.neg().sqrt();
+            return Some(b.scale(&(r.atan2(&a) / r)));
+        }
+        let r = square.sqrt();
+        if a <= r {
+            return None;
+        }
+        let theta = ((a.clone() + r.clone()) / (a - r.clone())).ln() * T::from_f64(0.5);
+        Some(b.scale(&(theta / r)))
+    }
+}
+
+impl<T> From<Motor<T>> for Cga<T, 4> where
+T: Real,
+{
+    /// The versor of a rigid motion of 3D PGA, normalizing it first.
+    fn from(motor: Motor<T>) -> Self {
+        let (rotation, translation) = motor.to_rotation_translation();
+        Self::translator(translation).geometric_product(&Self::from_rotor(&rotation))
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Cga, Motor, Vga};
+use crate::tests::{close, same};
+
+type C = Cga<f64, 4>;
+
+fn at(x: &C, y: [f64; 3]) -> bool {
+    let (a, b, c) = x.to_point().unwrap();
+    close(a, y[0]) && close(b, y[1]) && close(c, y[2])
+}
+
+fn point(x: [f64; 3]) -> C {
+    C::point(x[0], x[1], x[2])
+}
+
+#[test]
+fn transforms_points() {
+    let x = [1.0, -2.0, 0.5];
+    assert!(at(&C::translator([3.0, 1.0, -1.0]).apply(&point(x)), [4.0, -1.0, -0.5]));
+    assert!(at(&C::dilator(2.5).apply(&point(x)), [2.5, -5.0, 1.25]));
+
+    let r = Vga::from_axis_angle([0.0, 0.0, 1.0], core::f64::consts::FRAC_PI_2);
+    assert!(at(&C::from_rotor(&r).apply(&point(x)), [2.0, 1.0, 0.5]));
+
+    let b = [0.25, 0.5, -1.0];
+    let (bx, bb, xx) = (0.25 - 1.0 - 0.5, 0.0625 + 0.25 + 1.0, 1.0 + 4.0 + 0.25);
+    let d = 1.0 + 2.0 * bx + bb * xx;
+    assert!(at(&C::transversor(b).apply(&point(x)), core::array::from_fn(|i| (x[i] + xx * b[i]) / d)));
+    assert!(at(&C::transversor(b).apply(&C::origin()), [0.0, 0.0, 0.0]));
+
+    let y = C::inversion([1.0, 0.0, 0.0], 2.0).apply(&point([3.0, 4.0, 0.0]));
+    assert!(at(&y, [1.0 + 4.0 * 2.0 / 20.0, 4.0 * 4.0 / 20.0, 0.0]));
+    let rounded = C::inversion([1.0, 0.0, 0.0], 2.0) + 1e-17;
+    assert!(same(&rounded.apply(&point([3.0, 4.0, 0.0])), &C::inversion([1.0, 0.0, 0.0], 2.0).apply(&point([3.0, 4.0, 0.0]))));
+    assert!(at(&C::reflection([0.0, 2.0, 0.0], 2.0).apply(&point(x)), [1.0, 4.0, 0.5]));
+}
+
+#[test]
+fn spheres_to_spheres() {
+    let sphere = C::sphere([1.0, 2.0, -1.0], 4.0);
+    let versors = [
+        C::translator([0.5, 0.0, -2.0]),
+        C::dilator(3.0),
+        C::transversor([0.1, -0.2, 0.05]),
+        C::inversion([0.0, 0.0, 0.0], 1.5),
+        C::from_rotor(&Vga::from_axis_angle([1.0, 1.0, 0.0], 0.7)),
+    ];
+    let on = [[3.0, 2.0, -1.0], [1.0, 4.0, -1.0], [1.0, 2.0, 1.0], [-1.0, 2.0, -1.0]];
+    for v in &versors {
+        let image = v.apply(&sphere);
+        assert!(image.radius_squared() > 0.0);
+        for x in &on {
+            let p = v.apply(&point(*x));
+            assert_eq!(image.to_ipns().side(&p), core::cmp::Ordering::Equal);
+        }
+    }
+
+    let image = C::dilator(3.0).apply(&sphere);
+    let (x, y, z) = image.center().unwrap();
+    assert!(close(x, 3.0) && close(y, 6.0) && close(z, -3.0));
+    assert!(close(image.radius_squared(), 36.0));
+}
+
+#[test]
+fn motors() {
+    let a = Motor::from_rotation_translation(&Vga::from_axis_angle([1.0, 2.0, 2.0], 0.8), [1.0, -1.0, 3.0]);
+    let b = Motor::from_axis_angle([0.0, 1.0, 0.0], -1.3) * Motor::from_translation(0.5, 2.0, 0.0);
+    let (c, d) = (C::from(a), C::from(b));
+    assert!(same(&C::from(a * b), &c.geometric_product(&d)));
+
+    let x = [0.3, -1.0, 2.0];
+    let moved = a.transform_point(&crate::Pga::<f64, 3>::point(x[0], x[1], x[2])).undual().vector_part();
+    let expected = [moved[0] / moved[3], moved[1] / moved[3], moved[2] / moved[3]];
+    assert!(at(&c.apply(&point(x)), expected));
+
+    let scaled = C::dilator(2.0).geometric_product(&c);
+    assert!(at(&scaled.apply(&point(x)), expected.map(|y| 2.0 * y)));
+}
+
+#[test]
+fn logarithms() {
+    let r = C::from_rotor(&Vga::from_axis_angle([0.0, 0.0, 1.0], 2.5));
+    let versors = [
+        C::translator([1.0, -2.0, 0.5]),
+        C::dilator(0.2),
+        C::transversor([0.3, 0.0, -0.4]),
+        r.clone(),
+        C::from(Motor::from_axis_angle([1.0, 0.0, 1.0], 3.0) * Motor::from_translation(0.0, 2.0, -1.0)),
+        C::dilator(3.0).geometric_product(&r),
+        C::translator([0.0, 0.0, 2.0]).geometric_product(&r).geometric_product(&C::dilator(0.5)),
+        C::transversor([0.2, 0.1, 0.0]).geometric_product(&C::translator([1.0, 0.0, 2.0])),
+        C::from_rotor(&Vga::from_axis_angle([0.0, 1.0, 0.0], core::f64::consts::PI)).geometric_product(&C::dilator(2.0)),
+        C::from(Motor::from_axis_angle([0.0, 1.0, 0.0], core::f64::consts::PI) * Motor::from_translation(1.0, 3.0, 0.0)),
+    ];
+    for v in &versors {
+        let b = v.versor_log().unwrap();
+        assert!(same(&b.grade(2), &b));
+        assert!(same(&b.exp(), v));
+    }
+    let b = C::dilator(2.0).geometric_product(&r).versor_log().unwrap();
+    assert!(same(&b.scale(&0.5).exp().geometric_product(&b.scale(&0.5).exp()), &C::dilator(2.0).geometric_product(&r)));
+    assert!(same(&C::dilator(7.0).scale(&3.0).versor_log().unwrap().exp(), &C::dilator(7.0)));
+    assert!((-C::dilator(2.0)).versor_log().is_none());
+}
@@ -0,0 +1,198 @@
+use crate::multivector::{Clifford, Multivector, One, Real, Zero};
+use core::cmp::Ordering;
+
+#[cfg(test)]
+mod tests;
+
+
+/// Decompositions of versors into reflections and of bivectors into simple parts.
+impl<T, const C: Clifford> Multivector<T, C> where
+T: Real,
+[(); C.size()]: Sized,
+{
+    /// A scale and vectors squaring to `1` or `-1` whose geometric product times the scale is the versor
+    /// `self`, or `None` if `self` is not a versor.
+    ///
+    /// Each non-null basis vector `e` in turn is taken back from its image `y` by the reflection in `y - e`,
+    /// or in `y + e` and `e` when `y - e` is null, which keeps the vectors already restored. In Euclidean
+    /// algebras `y - e` is never null, which gives at most one reflection for each basis vector as in the
+    /// Cartan-Dieudonne theorem; in others the bound is two for each non-null basis vector.
+    ///
+    /// Null basis vectors are left out, so in degenerate algebras this only finds versors whose action on the
+    /// others determines them, such as the motors of PGA.
+    pub fn reflections(&self) -> Option<(T, Vec<Self>)> {
+        let tolerance = T::epsilon().sqrt();
+        let scale = self.euclidean_norm();
+        let mut rest = self.scale(&(T::one() / scale.clone()));
+        let mut factors = Vec::new();
+        for i in (0..C.dim()).filter(|i| C.zero_bits() & 1 << i == 0) {
+            let e = Self::basis(1 << i);
+            let y = rest.involute().geometric_product(&e).geometric_product(&rest.inverse()).grade(1);
+            let n = y.clone() - e.clone();
+            if n.euclidean_norm() <= tolerance {
+                continue;
+            }
+            let square = n.geometric_product(&n).scalar_part();
+            let reflectors = if square.abs() > tolerance.clone() * n.euclidean_norm() { vec![n] } else { vec![y + e.clone(), e] };
+            for v in reflectors {
+                let v = v.scale(&(T::one() / v.geometric_product(&v).scalar_part().abs().sqrt()));
+                rest = v.inverse().geometric_product(&rest);
+                factors.push(v);
+            }
+        }
+        let s = rest.scalar_part();
+        if (rest - s.clone()).euclidean_norm() > tolerance {
+            return None;
+        }
+        Some((s * scale, factors))
+    }
+
+    /// Commuting simple bivectors, with scalar squares, summing to the bivector `self`, at most one for
+    /// each pair of dimensions; `None` when they are not unique or cannot be found.
+    ///
+    /// Following Roelfs and De Keninck, the squares `l_i` of the parts are the roots of
+    /// `sum_m (-l)^(k - m) <W_m W_m>` for the wedge powers `W_m = self^m / m!`, and each part with a nonzero
+    /// square is `N_i D_i^-1` for sums `N_i` and `D_i` of the `W_m` with powers of `l_i`. A part with a zero
+    /// square, as for the translation along the axis of a screw motion in PGA, is what remains. The roots
+    /// have to be real and distinct, so isoclinic rotations, which split in many ways, give `None`.
+    ///
+    /// The exponential of `self` is the product of the exponentials of the parts, each in closed form.
+    pub fn invariant_decomposition(&self) -> Option<Vec<Self>> {
+        let k = C.dim() / 2;
+        let tolerance = T::epsilon().sqrt();
+        let size = self.euclidean_norm();
+        if size == T::zero() || k == 0 {
+            return Some(Vec::new());
+        }
+        let b = self.scale(&(T::one() / size.clone()));
+        let mut wedges = vec![Self::one()];
+        for m in 1..=k {
+            let w = wedges[m - 1].outer_product(&b).scale(&(T::one() / T::from_f64(m as f64)));
+            wedges.push(w);
+        }
+        // The characteristic polynomial with the coefficient of `l^(k - m)` at `m`.
+        let polynomial: Vec<T> = wedges.iter().enumerate().map(|(m, w)| {
+            let e = w.geometric_product(w).scalar_part();
+            if m % 2 == 0 { e } else { e.neg() }
+        }).collect();
+        let mut roots = real_roots(&polynomial)?;
+        if roots.iter().any(|l| l.is_nan()) {
+            return None;
+        }
+        roots.sort_by(|x, y| y.abs().partial_cmp(&x.abs()).unwrap_or(Ordering::Equal));
+        if roots.windows(2).any(|r| (r[0].clone() - r[1].clone()).abs() <= tolerance && r[0].abs() > tolerance) {
+            return None;
+        }
+
+        // The parts with nonzero squares come from the formula and the part with a zero square is the rest,
+        // or if there is none, the part of smallest square.
+        let nonzero = roots.iter().filter(|l| l.abs() > tolerance).count().min(k - 1);
+        let mut parts = Vec::new();
+        for l in &roots[..nonzero] {
+            let power = |p: usize| (0..p).fold(T::one(), |x, _| x * l.clone());
+            let (mut n, mut d) = (Self::zero(), Self::zero());
+            for (m, w) in wedges.iter().enumerate() {
+                if m % 2 == 0 {
+                    d += w.scale(&power((k - m) / 2));
+                } else {
+                    n += w.scale(&power((k - m).div_ceil(2)));
+                }
+            }
+            parts.push(right_divide(&n, &d)?.grade(2));
+        }
+        let last = parts.iter().fold(b, |r, p| r - p.clone());
+        let square = last.geometric_product(&last);
+        if (square.clone() - square.scalar_part()).euclidean_norm() > tolerance {
+            return None;
+        }
+        parts.push(last);
+        Some(parts.into_iter().filter(|p| p.euclidean_norm() > tolerance).map(|p| p.scale(&size)).collect())
+    }
+}
+
+/// The roots of `sum_m c[m] x^(n - m)` with `c[0] == 1`, by Newton's method from above the largest root
+/// and deflation, `None` unless all of them are real.
+fn real_roots<T: Real>(c: &[T]) -> Option<Vec<T>> {
+    let mut c = c.to_vec();
+    let mut roots = Vec::new();
+    while c.len() > 1 {
+        let bound = c.iter().skip(1).fold(T::one(), |b, x| b + x.abs());
+        let evaluate = |c: &[T], x: &T| c.iter().fold((T::zero(), T::zero()), |(p, d), a| {
+            (p.clone() * x.clone() + a.clone(), d * x.clone() + p)
+        });
+        let mut x = bound.clone();
+        for _ in 0..200 {
+            let (p, d) = evaluate(&c, &x);
+            if d == T::zero() {
+                break;
+            }
+            let step = p / d;
+            x -= step.clone();
+            if step.abs() <= T::epsilon() * (T::one() + x.abs()) {
+                break;
+            }
+        }
+        if evaluate(&c, &x).0.abs() > T::epsilon().sqrt() * bound.clone() * bound {
+            return None;
+        }
+        // Synthetic division by `x - root`.
+        let mut quotient = Vec::with_capacity(c.len() - 1);
+        let mut carry = T::zero();
+        for a in &c[..c.len() - 1] {
+            carry = carry * x.clone() + a.clone();
+            quotient.push(carry.clone());
+        }
+        c = quotient;
+        roots.push(x);
+    }
+    Some(roots)
+}
+
+/// The solution `x` of `x * d == n`, by Gaussian elimination on the matrix of right multiplication by `d`,
+/// `None` if `d` is not invertible.
+fn right_divide<T, const C: Clifford>(n: &Multivector<T, C>, d: &Multivector<T, C>) -> Option<Multivector<T, C>> where
+T: Real,
+[(); C.size()]: Sized,
+{
+    let size = C.size();
+    let columns: Vec<Multivector<T, C>> = (0..size).map(|j| {
+        let mut e = Multivector::zero();
+        e[j] = T::one();
+        e.geometric_product(d)
+    }).collect();
+    let a = (0..size).map(|i| (0..size).map(|j| columns[j][i].clone()).collect()).collect();
+    let solution = solve(a, (0..size).map(|i| n[i].clone()).collect())?;
+    let mut x = Multivector::zero();
+    for (i, c) in solution.into_iter().enumerate() {
+        x[i] = c;
+    }
+    Some(x)
+}
+
+/// The solution of `a x == b` by Gaussian elimination, `None` if `a` is singular.
+pub(crate) fn solve<T: Real>(mut a: Vec<Vec<T>>, b: Vec<T>) -> Option<Vec<T>> {
+    let n = b.len();
+    for (row, x) in a.iter_mut().zip(b) {
+        row.push(x);
+    }
+    let largest = a.iter().flatten().fold(T::zero(), |m, x| if x.abs() > m { x.abs() } else { m });
+    for col in 0..n {
+        let pivot = (col..n).fold(col, |p, r| if a[r][col].abs() > a[p][col].abs() { r } else { p });
+        if a[pivot][col].abs() <= T::epsilon().sqrt() * largest.clone() {
+            return None;
+        }
+        a.swap(col, pivot);
+        let p = a[col][col].clone();
+        let row: Vec<T> = a[col].iter().map(|x| x.clone() / p.clone()).collect();
+        for (r, other) in a.iter_mut().enumerate() {
+            if r != col {
+                let f = other[col].clone();
+                for (x, y) in other.iter_mut().zip(&row) {
+                    *x -= f.clone() * y.clone();
+                }
+            }
+        }
+        a[col] = row;
+    }
+    Some(a.into_iter().map(|row| row[n].clone()).collect())
+}
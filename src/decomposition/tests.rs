@@ -0,0 +1,71 @@
+use crate::{Multivector, Pga, Sta, Vga};
+use crate::multivector::{Clifford, Zero};
+use crate::tests::{close, same};
+
+/// Whether the versor is the product of its reflections.
+fn recomposes<const C: Clifford>(v: &Multivector<f64, C>, most: usize) -> bool where
+[(); C.size()]: Sized,
+{
+    let (scale, factors) = v.reflections().unwrap();
+    let product = factors.iter().fold(Multivector::from_scalar(scale), |p, f| p.geometric_product(f));
+    let units = factors.iter().all(|f| close(f.geometric_product(f).scalar_part().abs(), 1.0) && same(&f.grade(1), f));
+    factors.len() <= most && units && same(&product, v)
+}
+
+#[test]
+fn reflections() {
+    type V = Vga<f64, 4>;
+    let double = (V::basis(0b0011).scale(&0.4) + V::basis(0b1100).scale(&-1.2)).exp();
+    assert!(recomposes(&double, 4));
+    assert!(recomposes(&V::from_vector([1.0, 2.0, -0.5, 0.0]), 1));
+    let odd = V::from_vector([0.0, 1.0, 1.0, 3.0]).geometric_product(&double).scale(&2.5);
+    assert!(recomposes(&odd, 4));
+    assert!((Vga::<f64, 3>::from_vector([1.0, 0.0, 0.0]) + 1.0).reflections().is_none());
+
+    // The boost and the screw stay within the dimension too, though only twice the non-null vectors is certain.
+    let boost = Sta::<f64>::boost([1.0, 2.0, 0.0], 0.8).geometric_product(&Sta::rotation([0.0, 0.0, 1.0], 0.3));
+    assert!(recomposes(&boost, 4));
+
+    type P = Pga<f64, 3>;
+    let screw = (P::basis(0b0011).scale(&0.7) + P::basis(0b1100).scale(&1.5) + P::basis(0b1001).scale(&-0.4)).exp();
+    assert!(recomposes(&screw, 4));
+}
+
+#[test]
+fn invariant_decomposition() {
+    type V = Vga<f64, 4>;
+    let (a, b) = (V::basis(0b0011).scale(&0.4), V::basis(0b1100).scale(&-1.2));
+    let parts = (a.clone() + b.clone()).invariant_decomposition().unwrap();
+    assert_eq!(parts.len(), 2);
+    assert!(same(&parts[0], &b) && same(&parts[1], &a));
+    assert_eq!(V::basis(0b0101).invariant_decomposition().unwrap().len(), 1);
+    assert!((V::basis(0b0011) + V::basis(0b1100)).invariant_decomposition().is_none());
+
+    // A rotation in 6D by three different angles in general position.
+    type W = Vga<f64, 6>;
+    let x = W::from(core::array::from_fn(|i| (i as f64 * 0.37).sin())).grade(2);
+    let parts = x.invariant_decomposition().unwrap();
+    assert_eq!(parts.len(), 3);
+    assert!(same(&parts.iter().fold(W::zero(), |s, p| s + p.clone()), &x));
+    for (i, p) in parts.iter().enumerate() {
+        let square = p.geometric_product(p);
+        assert!(same(&square, &W::from_scalar(square.scalar_part())) && square.scalar_part() < 0.0);
+        assert!(parts[..i].iter().all(|q| same(&p.geometric_product(q), &q.geometric_product(p))));
+    }
+    let exp = parts.iter().fold(W::from_scalar(1.0), |r, p| r.geometric_product(&p.exp()));
+    assert!(same(&exp, &x.exp()));
+
+    // A screw motion splits into its rotation and the translation along its axis.
+    type P = Pga<f64, 3>;
+    let screw = P::basis(0b0011).scale(&0.7) + P::basis(0b1100).scale(&1.5) + P::basis(0b1001).scale(&-0.4);
+    let parts = screw.invariant_decomposition().unwrap();
+    assert_eq!(parts.len(), 2);
+    assert!(parts[0].geometric_product(&parts[0]).scalar_part() < 0.0);
+    assert!(same(&parts[1].geometric_product(&parts[1]), &P::zero()));
+    assert!(same(&(parts[0].clone() + parts[1].clone()), &screw));
+
+    // A boost and a rotation in the orthogonal plane, with squares of both signs.
+    let x = Sta::<f64>::basis(0b0011).scale(&0.5) + Sta::basis(0b1100).scale(&0.9);
+    let parts = x.invariant_decomposition().unwrap();
+    assert!(same(&parts[0], &Sta::basis(0b1100).scale(&0.9)) && same(&parts[1], &Sta::basis(0b0011).scale(&0.5)));
+}
@@ -0,0 +1,134 @@
+use crate::Dual;
+use crate::multivector::{Clifford, Multivector, One, Real, Zero};
+use core::cmp::Ordering;
+use core::ops::{Div, Neg};
+
+#[cfg(test)]
+mod tests;
+
+
+impl<T> Dual<T> where
+T: Zero,
+{
+    pub fn new(value: T, derivative: T) -> Self {
+        Self::from([value, derivative])
+    }
+
+    /// A constant, whose derivative vanishes.
+    pub fn constant(value: T) -> Self {
+        Self::from_scalar(value)
+    }
+
+    /// The independent variable, whose derivative is one.
+    pub fn variable(value: T) -> Self where
+    T: One,
+    {
+        Self::new(value, T::one())
+    }
+}
+
+impl<T> Dual<T> where
+T: Clone,
+{
+    pub fn value(&self) -> T {
+        self[0].clone()
+    }
+
+    pub fn derivative(&self) -> T {
+        self[1].clone()
+    }
+}
+
+impl<T> Dual<T> where
+T: Real,
+{
+    /// Apply `f` to the value and scale the derivative by `df`, the chain rule.
+    fn chain(&self, f: T, df: T) -> Self {
+        Self::new(f, df * self.derivative())
+    }
+}
+
+impl<T> Div for Dual<T> where
+T: Real,
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self::Output {
+        let (a, b) = (self.value(), self.derivative());
+        let (c, d) = (other.value(), other.derivative());
+        Self::new(a.clone() / c.clone(), (b * c.clone() - a * d) / (c.clone() * c))
+    }
+}
+
+impl<T> PartialOrd for Dual<T> where
+T: Real,
+{
+    /// Order by value, with dual numbers of equal value but different derivatives incomparable.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.value().partial_cmp(&other.value()) {
+            Some(Ordering::Equal) if self != other => None,
+            ordering => ordering,
+        }
+    }
+}
+
+impl<T> Real for Dual<T> where
+T: Real,
+{
+    fn from_f64(x: f64) -> Self {
+        Self::constant(T::from_f64(x))
+    }
+    fn epsilon() -> Self {
+        Self::constant(T::epsilon())
+    }
+    fn sqrt(&self) -> Self {
+        let root = self.value().sqrt();
+        self.chain(root.clone(), T::one() / (root.clone() + root))
+    }
+    fn abs(&self) -> Self {
+        if self.value() < T::zero() {
+            self.clone().neg()
+        } else {
+            self.clone()
+        }
+    }
+    fn exp(&self) -> Self {
+        let exp = self.value().exp();
+        self.chain(exp.clone(), exp)
+    }
+    fn ln(&self) -> Self {
+        self.chain(self.value().ln(), T::one() / self.value())
+    }
+    fn sin(&self) -> Self {
+        self.chain(self.value().sin(), self.value().cos())
+    }
+    fn cos(&self) -> Self {
+        self.chain(self.value().cos(), self.value().sin().neg())
+    }
+    fn sinh(&self) -> Self {
+        self.chain(self.value().sinh(), self.value().cosh())
+    }
+    fn cosh(&self) -> Self {
+        self.chain(self.value().cosh(), self.value().sinh())
+    }
+    fn atan2(&self, other: &Self) -> Self {
+        let (y, x) = (self.value(), other.value());
+        let derivative = (x.clone() * self.derivative() - y.clone() * other.derivative()) / (x.clone() * x.clone() + y.clone() * y.clone());
+        Self::new(y.atan2(&x), derivative)
+    }
+}
+
+/// The Jacobian of `f` at `x`, as the derivative of the output along each coefficient of the input.
+///
+/// Entry `i` holds the partial derivatives of all output coefficients with respect to input coefficient `i`.
+pub fn jacobian<T, F, const C: Clifford, const D: Clifford>(f: F, x: &Multivector<T, C>) -> Vec<Multivector<T, D>> where
+T: Real,
+F: Fn(&Multivector<Dual<T>, C>) -> Multivector<Dual<T>, D>,
+[(); C.size()]: Sized,
+[(); D.size()]: Sized,
+{
+    (0..C.size()).map(|i| {
+        let mut seeded = x.map(|v| Dual::constant(v.clone()));
+        seeded[i] = Dual::variable(x[i].clone());
+        f(&seeded).map(Dual::derivative)
+    }).collect()
+}
@@ -0,0 +1,60 @@
+use crate::{Dual, Multivector, Real, Vga, jacobian};
+use crate::multivector::vga;
+use crate::tests::close;
+
+const VGA3: crate::Clifford = vga(3);
+
+#[test]
+fn chain_rule() {
+    let x = Dual::variable(0.7f64);
+    assert!(close((&(&x * &x) * &x).derivative(), 3.0 * 0.7 * 0.7));
+    assert!(close(x.sqrt().derivative(), 0.5 / 0.7f64.sqrt()));
+    assert!(close(x.ln().derivative(), 1.0 / 0.7));
+    assert!(close(x.sin().derivative(), 0.7f64.cos()));
+    assert!(close((Dual::constant(1.0) / x.clone()).derivative(), -1.0 / (0.7 * 0.7)));
+    assert!(close(x.atan2(&Dual::constant(2.0)).derivative(), 2.0 / (4.0 + 0.7 * 0.7)));
+}
+
+#[test]
+fn sandwich_jacobian_is_rotation() {
+    let rotor = Vga::<f64, 3>::from([0.8, 0.0, 0.0, 0.0, 0.6, 0.0, 0.0, 0.0]);
+    let lifted = rotor.map(|v| Dual::constant(*v));
+    let x = Vga::<f64, 3>::from_vector([1.0, 2.0, 3.0]);
+    let columns = jacobian(|x: &Multivector<Dual<f64>, VGA3>| lifted.sandwich(x), &x);
+    for i in 0..3 {
+        let expected = rotor.sandwich(&Vga::<f64, 3>::basis(1 << i));
+        let column = &columns[VGA3.bit_to_blade(1 << i)];
+        for j in 0..VGA3.size() {
+            assert!(close(column[j], expected[j]));
+        }
+    }
+}
+
+#[test]
+fn exp_jacobian_matches_finite_differences() {
+    let h = 1e-6;
+    let bivector = Vga::<f64, 3>::from([0.0, 0.0, 0.0, 0.0, 0.3, -0.2, 0.5, 0.0]);
+    let columns = jacobian(|x: &Multivector<Dual<f64>, VGA3>| x.exp(), &bivector);
+    for i in 4..7 {
+        let mut forward = bivector.clone();
+        forward[i] += h;
+        let mut backward = bivector.clone();
+        backward[i] -= h;
+        let difference = forward.exp() - backward.exp();
+        for j in 0..VGA3.size() {
+            assert!((columns[i][j] - difference[j] / (2.0 * h)).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn log_of_exp_has_identity_jacobian() {
+    let bivector = Vga::<f64, 3>::from([0.0, 0.0, 0.0, 0.0, 0.3, -0.2, 0.5, 0.0]);
+    let columns = jacobian(|x: &Multivector<Dual<f64>, VGA3>| x.grade(2).exp().log().unwrap(), &bivector);
+    for (i, column) in columns.iter().enumerate() {
+        for j in 0..VGA3.size() {
+            let expected = if i == j && (4..7).contains(&i) { 1.0 } else { 0.0 };
+            assert!(close(column[j], expected));
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::Vga;
+use crate::motor::{quaternion_product, quaternion_to_rotor, rotor_to_quaternion};
+use crate::multivector::Real;
+
+#[cfg(test)]
+mod tests;
+
+
+/// The axes of the three elementary rotations of Euler angles, Tait-Bryan sequences like `XYZ` with three
+/// distinct axes and proper Euler sequences like `ZXZ` repeating the first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerAxes {
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+impl EulerAxes {
+    pub const ALL: [EulerAxes; 12] = [
+        EulerAxes::XYZ, EulerAxes::XZY, EulerAxes::YXZ, EulerAxes::YZX, EulerAxes::ZXY, EulerAxes::ZYX,
+        EulerAxes::XYX, EulerAxes::XZX, EulerAxes::YXY, EulerAxes::YZY, EulerAxes::ZXZ, EulerAxes::ZYZ,
+    ];
+
+    fn indices(self) -> [usize; 3] {
+        match self {
+            EulerAxes::XYZ => [0, 1, 2],
+            EulerAxes::XZY => [0, 2, 1],
+            EulerAxes::YXZ => [1, 0, 2],
+            EulerAxes::YZX => [1, 2, 0],
+            EulerAxes::ZXY => [2, 0, 1],
+            EulerAxes::ZYX => [2, 1, 0],
+            EulerAxes::XYX => [0, 1, 0],
+            EulerAxes::XZX => [0, 2, 0],
+            EulerAxes::YXY => [1, 0, 1],
+            EulerAxes::YZY => [1, 2, 1],
+            EulerAxes::ZXZ => [2, 0, 2],
+            EulerAxes::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+/// Whether the elementary rotations of Euler angles turn about the axes of the fixed frame or of the frame
+/// moving with the rotated body.
+///
+/// Intrinsic rotations about `a, b, c` are the extrinsic rotations about `c, b, a` with the angles reversed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EulerFrame {
+    Extrinsic,
+    Intrinsic,
+}
+
+/// The angle within `[-pi, pi]` equivalent to `x` in `[-3 pi, 3 pi]`.
+fn wrap<T: Real>(x: T) -> T {
+    let pi = T::from_f64(core::f64::consts::PI);
+    if x < pi.clone().neg() {
+        x + pi.clone() + pi
+    } else if x > pi {
+        x - pi.clone() - pi
+    } else {
+        x
+    }
+}
+
+/// Rotor conversions from and to Euler angles, axis-angle pairs and `(w, x, y, z)` quaternions.
+///
+/// The quaternion units are `i = -e23`, `j = e13` and `k = -e12`, so that `(cos(a / 2), sin(a / 2), 0, 0)`
+/// turns counterclockwise by `a` about the x axis, like the rotor `cos(a / 2) - sin(a / 2) e23`.
+impl<T> Vga<T, 3> where
+T: Real,
+{
+    pub fn from_quaternion(q: [T; 4]) -> Self {
+        quaternion_to_rotor(q)
+    }
+
+    pub fn to_quaternion(&self) -> [T; 4] {
+        rotor_to_quaternion(self)
+    }
+
+    /// The rotor turning by `angle` about `axis`, counterclockwise when looking against it.
+    pub fn from_axis_angle(axis: [T; 3], angle: T) -> Self {
+        let half = angle * T::from_f64(0.5);
+        let s = half.sin() / axis.iter().fold(T::zero(), |n, x| n + x.clone() * x.clone()).sqrt();
+        let [x, y, z] = axis.map(|c| c * s.clone());
+        Self::from_quaternion([half.cos(), x, y, z])
+    }
+
+    /// The unit axis and angle in `[0, pi]` of a rotor, with the x axis for the identity.
+    pub fn to_axis_angle(&self) -> ([T; 3], T) {
+        let [w, x, y, z] = self.to_quaternion();
+        let s = (x.clone() * x.clone() + y.clone() * y.clone() + z.clone() * z.clone()).sqrt();
+        if s == T::zero() {
+            return ([T::one(), T::zero(), T::zero()], T::zero());
+        }
+        let angle = s.atan2(&w.abs()) * T::from_f64(2.0);
+        let s = if w < T::zero() { s.neg() } else { s };
+        ([x / s.clone(), y / s.clone(), z / s], angle)
+    }
+
+    /// The rotor turning by the length of `v` about its direction.
+    pub fn from_scaled_axis(v: [T; 3]) -> Self {
+        let square = v.iter().fold(T::zero(), |n, x| n + x.clone() * x.clone());
+        let angle = square.sqrt();
+        let half = angle.clone() * T::from_f64(0.5);
+        // sin(angle / 2) / angle, by series near zero.
+        let k = if square < T::epsilon().sqrt() {
+            T::from_f64(0.5) - square / T::from_f64(48.0)
+        } else {
+            half.sin() / angle
+        };
+        let [x, y, z] = v.map(|c| c * k.clone());
+        Self::from_quaternion([half.cos(), x, y, z])
+    }
+
+    /// The axis scaled by the angle in `[0, pi]`, the inverse of `from_scaled_axis`.
+    pub fn to_scaled_axis(&self) -> [T; 3] {
+        let [w, x, y, z] = self.to_quaternion();
+        let n = (w.clone() * w.clone() + x.clone() * x.clone() + y.clone() * y.clone() + z.clone() * z.clone()).sqrt();
+        let w = w / n.clone();
+        let v = [x, y, z].map(|c| c / n.clone());
+        let square = v.iter().fold(T::zero(), |n, x| n + x.clone() * x.clone());
+        let s = square.sqrt();
+        // angle / sin(angle / 2), by series near zero.
+        let k = if square < T::epsilon().sqrt() {
+            T::from_f64(2.0) / w.clone() * (T::one() - square / (T::from_f64(3.0) * w.clone() * w.clone()))
+        } else {
+            s.atan2(&w.abs()) * T::from_f64(2.0) / s
+        };
+        let k = if w < T::zero() { k.neg() } else { k };
+        v.map(|c| c * k.clone())
+    }
+
+    /// The rotor of Euler angles, turning by `angles[n]` about `axes` in order.
+    pub fn from_euler(axes: EulerAxes, frame: EulerFrame, angles: [T; 3]) -> Self {
+        let half = T::from_f64(0.5);
+        let mut q = [T::one(), T::zero(), T::zero(), T::zero()];
+        for (axis, angle) in axes.indices().iter().zip(angles) {
+            let half = angle * half.clone();
+            let mut r = [half.cos(), T::zero(), T::zero(), T::zero()];
+            r[*axis + 1] = half.sin();
+            q = match frame {
+                EulerFrame::Extrinsic => quaternion_product(&r, &q),
+                EulerFrame::Intrinsic => quaternion_product(&q, &r),
+            };
+        }
+        Self::from_quaternion(q)
+    }
+
+    /// The Euler angles of a rotor, with the first and last in `[-pi, pi]` and the middle one in `[0, pi]` for
+    /// proper Euler sequences and `[-pi / 2, pi / 2]` for Tait-Bryan sequences.
+    ///
+    /// In gimbal lock, when the first and last axes line up, only their combined angle is determined; it is
+    /// then put into the first angle of extrinsic and the last angle of intrinsic sequences, the other zero.
+    ///
+    /// This is the direct method of Bernardes and Viollet, reading the angles from the quaternion in one pass
+    /// for every sequence.
+    pub fn to_euler(&self, axes: EulerAxes, frame: EulerFrame) -> [T; 3] {
+        let [mut i, j, mut k] = axes.indices();
+        if frame == EulerFrame::Intrinsic {
+            core::mem::swap(&mut i, &mut k);
+        }
+        let proper = i == k;
+        if proper {
+            k = 3 - i - j;
+        }
+        let odd = (i + 1) % 3 != j;
+        let q = self.to_quaternion();
+        let (w, qi, qj) = (q[0].clone(), q[i + 1].clone(), q[j + 1].clone());
+        let qk = if odd { q[k + 1].clone().neg() } else { q[k + 1].clone() };
+        let (a, b, c, d) = if proper {
+            (w, qi, qj, qk)
+        } else {
+            (w.clone() - qj.clone(), qi.clone() + qk.clone(), qj + w, qk - qi)
+        };
+
+        let hypot = |x: &T, y: &T| (x.clone() * x.clone() + y.clone() * y.clone()).sqrt();
+        let two = T::from_f64(2.0);
+        let pi = T::from_f64(core::f64::consts::PI);
+        let tolerance = T::epsilon().sqrt();
+        let mut angles = [T::zero(), hypot(&c, &d).atan2(&hypot(&a, &b)) * two.clone(), T::zero()];
+        let half_sum = b.atan2(&a);
+        let half_diff = d.atan2(&c);
+        if angles[1].abs() <= tolerance {
+            angles[0] = half_sum * two;
+        } else if (angles[1].clone() - pi.clone()).abs() <= tolerance {
+            angles[0] = (half_diff * two).neg();
+        } else {
+            angles[0] = half_sum.clone() - half_diff.clone();
+            angles[2] = half_sum + half_diff;
+        }
+        if !proper {
+            if odd {
+                angles[2] = angles[2].clone().neg();
+            }
+            angles[1] = angles[1].clone() - pi * T::from_f64(0.5);
+        }
+        if frame == EulerFrame::Intrinsic {
+            angles.swap(0, 2);
+        }
+        angles.map(wrap)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{EulerAxes, EulerFrame, One, Vga};
+use crate::tests::{close, same};
+use core::f64::consts::{FRAC_PI_2, PI};
+use quickcheck::QuickCheck;
+
+type V = Vga<f64, 3>;
+
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Extrinsic, EulerFrame::Intrinsic];
+
+/// Whether two rotors are the same rotation, i.e. equal up to sign.
+fn same_rotation(lhs: &V, rhs: &V) -> bool {
+    same(lhs, rhs) || same(lhs, &-rhs.clone())
+}
+
+fn proper(axes: EulerAxes) -> bool {
+    matches!(axes, EulerAxes::XYX | EulerAxes::XZX | EulerAxes::YXY | EulerAxes::YZY | EulerAxes::ZXZ | EulerAxes::ZYZ)
+}
+
+/// An angle in `(-pi, pi)` from arbitrary bits.
+fn angle(x: i16) -> f64 {
+    (x as f64 + 0.5) / 32768.0 * PI
+}
+
+#[test]
+fn elementary_rotations() {
+    let x = V::from_axis_angle([1.0, 0.0, 0.0], FRAC_PI_2);
+    let y = V::from_axis_angle([0.0, 1.0, 0.0], FRAC_PI_2);
+    let v = x.sandwich(&V::from_vector([0.0, 1.0, 0.0])).vector_part();
+    assert!(close(v[0], 0.0) && close(v[1], 0.0) && close(v[2], 1.0));
+
+    let extrinsic = V::from_euler(EulerAxes::XYZ, EulerFrame::Extrinsic, [FRAC_PI_2, FRAC_PI_2, 0.0]);
+    assert!(same_rotation(&extrinsic, &(&y * &x)));
+    let intrinsic = V::from_euler(EulerAxes::XYZ, EulerFrame::Intrinsic, [FRAC_PI_2, FRAC_PI_2, 0.0]);
+    assert!(same_rotation(&intrinsic, &(&x * &y)));
+    assert!(same_rotation(&V::from_quaternion([0.5f64.sqrt(), 0.5f64.sqrt(), 0.0, 0.0]), &x));
+}
+
+#[test]
+fn axis_angle() {
+    let r = V::from_axis_angle([1.0, -2.0, 2.0], 2.5);
+    let (axis, angle) = r.to_axis_angle();
+    assert!(close(angle, 2.5) && close(axis[0], 1.0 / 3.0) && close(axis[1], -2.0 / 3.0) && close(axis[2], 2.0 / 3.0));
+    let (axis, angle) = (-r.clone()).to_axis_angle();
+    assert!(close(angle, 2.5) && close(axis[0], 1.0 / 3.0));
+    assert_eq!(V::one().to_axis_angle(), ([1.0, 0.0, 0.0], 0.0));
+    let v = r.to_scaled_axis();
+    assert!(close(v[0], 2.5 / 3.0) && close(v[1], -5.0 / 3.0) && close(v[2], 5.0 / 3.0));
+    let tiny = [1e-9, -2e-9, 3e-10];
+    assert!(V::from_scaled_axis(tiny).to_scaled_axis().iter().zip(tiny).all(|(a, b)| (a - b).abs() < 1e-20));
+}
+
+#[test]
+fn gimbal_lock() {
+    for axes in EulerAxes::ALL {
+        let middle = if proper(axes) { [0.0, PI] } else { [FRAC_PI_2, -FRAC_PI_2] };
+        for frame in FRAMES {
+            for beta in middle {
+                let r = V::from_euler(axes, frame, [0.4, beta, -1.1]);
+                let angles = r.to_euler(axes, frame);
+                assert!(same_rotation(&V::from_euler(axes, frame, angles), &r), "{:?} {:?} {}", axes, frame, beta);
+                assert!(close(angles[1], beta));
+                let zero = if frame == EulerFrame::Extrinsic { angles[2] } else { angles[0] };
+                assert_eq!(zero, 0.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn prop_euler_round_trip() {
+    fn round_trip((a, b, c, d): (i16, i16, i16, i16)) -> bool {
+        let r = V::from_quaternion([a, b, c, d].map(|x| x as f64 + 0.5)).normalized();
+        EulerAxes::ALL.iter().all(|axes| FRAMES.iter().all(|frame| {
+            let angles = r.to_euler(*axes, *frame);
+            let middle = if proper(*axes) { (0.0..=PI).contains(&angles[1]) } else { angles[1].abs() <= FRAC_PI_2 };
+            middle && same_rotation(&V::from_euler(*axes, *frame, angles), &r)
+        }))
+    }
+    QuickCheck::new().quickcheck(round_trip as fn((i16, i16, i16, i16)) -> bool);
+}
+
+#[test]
+fn prop_euler_angles_recovered() {
+    fn recovered((a, b, c): (i16, i16, i16)) -> bool {
+        EulerAxes::ALL.iter().all(|axes| FRAMES.iter().all(|frame| {
+            let middle = if proper(*axes) { angle(b).abs() } else { angle(b) / 2.0 };
+            let angles = [angle(a), middle, angle(c)];
+            if middle.abs() < 1e-3 || (FRAC_PI_2 - middle.abs()).abs() < 1e-3 || (PI - middle).abs() < 1e-3 {
+                return true;
+            }
+            let found = V::from_euler(*axes, *frame, angles).to_euler(*axes, *frame);
+            found.iter().zip(angles).all(|(x, y)| (x - y).abs() < 1e-6)
+        }))
+    }
+    QuickCheck::new().quickcheck(recovered as fn((i16, i16, i16)) -> bool);
+}
+
+#[test]
+fn prop_axis_angle_round_trip() {
+    fn round_trip((a, b, c, d): (i16, i16, i16, i16)) -> bool {
+        let r = V::from_quaternion([a, b, c, d].map(|x| x as f64 + 0.5)).normalized();
+        let (axis, angle) = r.to_axis_angle();
+        (0.0..=PI).contains(&angle)
+            && same_rotation(&V::from_axis_angle(axis, angle), &r)
+            && same_rotation(&V::from_scaled_axis(r.to_scaled_axis()), &r)
+    }
+    QuickCheck::new().quickcheck(round_trip as fn((i16, i16, i16, i16)) -> bool);
+}
@@ -0,0 +1,157 @@
+use crate::Outermorphism;
+use crate::multivector::{Clifford, Multivector, Real};
+
+#[cfg(test)]
+mod tests;
+
+
+/// An algebra on basis vectors `f_i` with an arbitrary symmetric bilinear form `f_i . f_j == form[i][j]`,
+/// such as the null vectors `n_o` and `n_inf` of CGA or a Witt basis of `Cl(n, n)`.
+///
+/// Multivectors keep their coefficients on the blades `f_i ^ f_j ^ ...` of the given basis in a
+/// `Multivector<T, C>`, where `C` is an orthogonal algebra of the same signature. Products depending on the
+/// metric go through `C`, mapping both arguments to its basis by an outermorphism and the result back; the
+/// outer product, the grade projections and the involutions do not depend on the metric and apply directly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Form<T, const C: Clifford> {
+    /// The map taking each `f_i` to its components on the orthogonal basis.
+    to_orthogonal: Outermorphism<T, C>,
+    from_orthogonal: Outermorphism<T, C>,
+}
+
+impl<T, const C: Clifford> Form<T, C> where
+T: Real,
+[(); C.size()]: Sized,
+{
+    /// The basis with the inner products `form[i][j]`, `None` unless `form` is symmetric with as many
+    /// positive, negative and zero eigenvalues as `C` has basis vectors squaring to `1`, `-1` and `0`.
+    ///
+    /// The form is diagonalized by Jacobi rotations, with eigenvalues below `sqrt(epsilon)` times the largest
+    /// taken as zero, and each eigenvector scaled to a unit vector of `C`.
+    pub fn new(form: &[Vec<T>]) -> Option<Self> {
+        let n = C.dim();
+        if form.len() != n || form.iter().any(|row| row.len() != n) {
+            return None;
+        }
+        if (0..n).any(|i| (0..i).any(|j| form[i][j] != form[j][i])) {
+            return None;
+        }
+        let (values, vectors) = eigen(form);
+        let largest = values.iter().fold(T::zero(), |m, x| if x.abs() > m { x.abs() } else { m });
+        let tolerance = T::epsilon().sqrt() * (T::one() + largest);
+        let sign = |x: &T| if x.abs() <= tolerance { 0 } else if *x < T::zero() { -1 } else { 1 };
+        let metric = |j: usize| if C.zero_bits() & 1 << j != 0 {
+            0
+        } else if C.negative_bits() & 1 << j != 0 {
+            -1
+        } else {
+            1
+        };
+
+        let mut to_orthogonal = vec![vec![T::zero(); n]; n];
+        let mut from_orthogonal = vec![vec![T::zero(); n]; n];
+        let mut used = vec![false; n];
+        for (a, value) in values.iter().enumerate() {
+            let j = (0..n).find(|&j| !used[j] && metric(j) == sign(value))?;
+            used[j] = true;
+            let scale = if sign(value) == 0 { T::one() } else { value.abs().sqrt() };
+            for i in 0..n {
+                to_orthogonal[j][i] = vectors[i][a].clone() * scale.clone();
+                from_orthogonal[i][j] = vectors[i][a].clone() / scale.clone();
+            }
+        }
+        Some(Form {
+            to_orthogonal: Outermorphism::new(to_orthogonal)?,
+            from_orthogonal: Outermorphism::new(from_orthogonal)?,
+        })
+    }
+
+    /// The same element on the orthogonal basis of `C`.
+    pub fn to_orthogonal(&self, x: &Multivector<T, C>) -> Multivector<T, C> {
+        self.to_orthogonal.apply(x)
+    }
+
+    /// The element of `C` on the basis of the form.
+    pub fn from_orthogonal(&self, x: &Multivector<T, C>) -> Multivector<T, C> {
+        self.from_orthogonal.apply(x)
+    }
+
+    /// Apply a product of `C` on the orthogonal basis.
+    fn through<F>(&self, x: &Multivector<T, C>, y: &Multivector<T, C>, f: F) -> Multivector<T, C> where
+    F: Fn(&Multivector<T, C>, &Multivector<T, C>) -> Multivector<T, C>,
+    {
+        self.from_orthogonal(&f(&self.to_orthogonal(x), &self.to_orthogonal(y)))
+    }
+
+    pub fn geometric_product(&self, x: &Multivector<T, C>, y: &Multivector<T, C>) -> Multivector<T, C> {
+        self.through(x, y, Multivector::geometric_product)
+    }
+
+    pub fn left_contraction(&self, x: &Multivector<T, C>, y: &Multivector<T, C>) -> Multivector<T, C> {
+        self.through(x, y, Multivector::left_contraction)
+    }
+
+    pub fn right_contraction(&self, x: &Multivector<T, C>, y: &Multivector<T, C>) -> Multivector<T, C> {
+        self.through(x, y, Multivector::right_contraction)
+    }
+
+    /// The scalar product, i.e. the scalar part of the geometric product, `form[i][j]` for `f_i` and `f_j`.
+    pub fn inner_product(&self, x: &Multivector<T, C>, y: &Multivector<T, C>) -> T {
+        self.to_orthogonal(x).inner_product(&self.to_orthogonal(y))
+    }
+
+    /// The sandwich product `v * x * v.reverse()`.
+    pub fn sandwich(&self, v: &Multivector<T, C>, x: &Multivector<T, C>) -> Multivector<T, C> {
+        self.through(v, x, Multivector::sandwich)
+    }
+
+    /// The inverse of a versor or blade.
+    pub fn inverse(&self, x: &Multivector<T, C>) -> Multivector<T, C> {
+        self.from_orthogonal(&self.to_orthogonal(x).inverse())
+    }
+}
+
+/// The eigenvalues and the orthonormal eigenvectors, as columns, of a symmetric matrix by cyclic Jacobi
+/// rotations.
+pub(crate) fn eigen<T>(m: &[Vec<T>]) -> (Vec<T>, Vec<Vec<T>>) where
+T: Real,
+{
+    let n = m.len();
+    let mut a = m.to_vec();
+    let mut v: Vec<Vec<T>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { T::one() } else { T::zero() }).collect())
+        .collect();
+    for _ in 0..64 {
+        let off = (0..n).fold(T::zero(), |s, i| (0..n).filter(|&j| j != i).fold(s, |s, j| s + a[i][j].clone() * a[i][j].clone()));
+        let total = (0..n).fold(off.clone(), |s, i| s + a[i][i].clone() * a[i][i].clone());
+        if off <= T::epsilon() * T::epsilon() * total {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q] == T::zero() {
+                    continue;
+                }
+                let theta = (a[q][q].clone() - a[p][p].clone()) / (a[p][q].clone() * T::from_f64(2.0));
+                let root = (theta.clone() * theta.clone() + T::one()).sqrt();
+                let t = if theta < T::zero() {
+                    T::one().neg() / (root - theta)
+                } else {
+                    T::one() / (theta + root)
+                };
+                let c = T::one() / (t.clone() * t.clone() + T::one()).sqrt();
+                let s = t * c.clone();
+                let rotate = |x: T, y: T| (c.clone() * x.clone() - s.clone() * y.clone(), s.clone() * x + c.clone() * y);
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (x, y) = rotate(row[p].clone(), row[q].clone());
+                    row[p] = x;
+                    row[q] = y;
+                }
+                let (row_p, row_q) = a[p].iter().zip(&a[q]).map(|(x, y)| rotate(x.clone(), y.clone())).unzip();
+                a[p] = row_p;
+                a[q] = row_q;
+            }
+        }
+    }
+    ((0..n).map(|i| a[i][i].clone()).collect(), v)
+}
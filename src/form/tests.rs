@@ -0,0 +1,76 @@
+use crate::{Cga, Form, Multivector};
+use crate::multivector::{cga, Clifford, One, Zero};
+use crate::tests::same;
+
+/// 3D CGA on the basis `e1, e2, e3, n_o, n_inf`.
+fn null_basis() -> Form<f64, { cga(4) }> {
+    let mut form = vec![vec![0.0; 5]; 5];
+    for (i, row) in form.iter_mut().enumerate().take(3) {
+        row[i] = 1.0;
+    }
+    form[3][4] = -1.0;
+    form[4][3] = -1.0;
+    Form::new(&form).unwrap()
+}
+
+#[test]
+fn null_basis_products() {
+    type M = Cga<f64, 4>;
+    let form = null_basis();
+    let (o, inf) = (M::basis(0b01000), M::basis(0b10000));
+    assert!(same(&form.geometric_product(&o, &o), &M::zero()));
+    assert!(same(&form.geometric_product(&inf, &inf), &M::zero()));
+    assert!((form.inner_product(&o, &inf) + 1.0).abs() < 1e-12);
+    let e = o.outer_product(&inf);
+    assert!(same(&form.geometric_product(&o, &inf), &(e.clone() - 1.0)));
+    assert!(same(&form.geometric_product(&e, &e), &M::one()));
+    assert!(same(&form.left_contraction(&inf, &e), &-inf.clone()));
+
+    // Points `x + n_o + x^2 / 2 n_inf` are null, with `p . q == -|p - q|^2 / 2`.
+    let (p, q) = (M::from_vector([1.0, -2.0, 0.5, 1.0, 2.625]), M::from_vector([0.0, 1.0, 0.0, 1.0, 0.5]));
+    assert!(form.inner_product(&p, &p).abs() < 1e-12);
+    assert!((form.inner_product(&p, &q) + 5.125).abs() < 1e-12);
+    assert!(same(&form.from_orthogonal(&form.to_orthogonal(&e)), &e));
+    let v = M::from_vector([1.0, 2.0, 0.0, 0.0, 3.0]);
+    assert!(same(&form.geometric_product(&v, &form.inverse(&v)), &M::one()));
+}
+
+#[test]
+fn witt_basis() {
+    type M = Multivector<f64, { Clifford::new(2, 2, 0) }>;
+    let form = Form::<f64, { Clifford::new(2, 2, 0) }>::new(&[
+        vec![0.0, 0.0, 1.0, 0.0],
+        vec![0.0, 0.0, 0.0, 1.0],
+        vec![1.0, 0.0, 0.0, 0.0],
+        vec![0.0, 1.0, 0.0, 0.0],
+    ]).unwrap();
+    for i in 0..4 {
+        assert!(same(&form.geometric_product(&M::basis(1 << i), &M::basis(1 << i)), &M::zero()));
+    }
+    let (a, b) = (M::basis(0b0001), M::basis(0b0100));
+    let anticommutator = form.geometric_product(&a, &b) + form.geometric_product(&b, &a);
+    assert!(same(&anticommutator, &M::from_scalar(2.0)));
+    assert!(same(&form.geometric_product(&a, &M::basis(0b0010)), &M::basis(0b0011)));
+}
+
+#[test]
+fn degenerate_and_mismatched() {
+    assert!(Form::<f64, { Clifford::new(1, 0, 1) }>::new(&[vec![1.0, 1.0], vec![1.0, 1.0]]).is_some());
+    assert!(Form::<f64, { Clifford::new(2, 0, 0) }>::new(&[vec![0.0, 1.0], vec![1.0, 0.0]]).is_none());
+    assert!(Form::<f64, { Clifford::new(1, 1, 0) }>::new(&[vec![0.0, 1.0], vec![2.0, 0.0]]).is_none());
+}
+
+#[test]
+fn complementary_layout() {
+    // `e31` is laid out in place of `e13`, so the product of the basis vectors is not always the basis blade.
+    const C: Clifford = Clifford::new(3, 0, 0).lexicographic().complementary();
+    type M = Multivector<f64, C>;
+    let form = Form::<f64, C>::new(&[
+        vec![1.0, 0.0, 0.0],
+        vec![0.0, 1.0, 0.0],
+        vec![0.0, 0.0, 1.0],
+    ]).unwrap();
+    let e31 = M::basis(0b101);
+    assert!(same(&form.to_orthogonal(&e31), &e31));
+    assert!(same(&form.geometric_product(&M::basis(0b100), &M::basis(0b001)), &e31));
+}
@@ -0,0 +1,73 @@
+use crate::multivector::{Clifford, Multivector, One, Real, Zero};
+
+#[cfg(test)]
+mod tests;
+
+
+/// Frames of vectors spanning a subspace which need not be orthonormal, nor the whole space.
+///
+/// The blade `E = a_1 ^ a_2 ^ ... ^ a_k` of a frame is the pseudoscalar of its subspace, and frames whose
+/// blade squares to zero, as for dependent vectors or null subspaces, have no reciprocal frame.
+impl<T, const C: Clifford> Multivector<T, C> where
+T: Real,
+[(); C.size()]: Sized,
+{
+    /// The vectors `a^i` of the same subspace with `a^i . a_j == 1` for `i == j` and `0` otherwise,
+    /// `a^i = (-1)^(i - 1) (a_1 ^ ... ^ a_(i - 1) ^ a_(i + 1) ^ ... ^ a_k) E^-1`, the dual in the subspace of the
+    /// outer product of the others.
+    pub fn reciprocal_frame(frame: &[Self]) -> Option<Vec<Self>> {
+        let blade = frame.iter().fold(Self::one(), |b, a| b.outer_product(a));
+        let inverse = blade_inverse(&blade)?;
+        Some((0..frame.len()).map(|i| {
+            let others = frame.iter().enumerate().filter(|&(j, _)| j != i).fold(Self::one(), |b, (_, a)| b.outer_product(a));
+            let x = others.geometric_product(&inverse).grade(1);
+            if i % 2 == 0 { x } else { -x }
+        }).collect())
+    }
+
+    /// The orthonormal frame of Gram and Schmidt, with each vector squaring to `1` or `-1` and the first `i`
+    /// spanning the same subspace with the same orientation as the first `i` of `frame`.
+    ///
+    /// Each vector is `A_(i - 1)^-1 A_i` for the blades `A_i = a_1 ^ ... ^ a_i`, the rejection of `a_i` from
+    /// the subspace of the vectors before it.
+    pub fn gram_schmidt(frame: &[Self]) -> Option<Vec<Self>> {
+        let mut blade = Self::one();
+        let mut vectors = Vec::new();
+        for a in frame {
+            let next = blade.outer_product(a);
+            let u = blade_inverse(&blade)?.geometric_product(&next).grade(1);
+            let square = u.geometric_product(&u).scalar_part();
+            if square.abs() <= T::epsilon() * u.euclidean_norm() * u.euclidean_norm() {
+                return None;
+            }
+            vectors.push(u.scale(&(T::one() / square.abs().sqrt())));
+            blade = next;
+        }
+        blade_inverse(&blade)?;
+        Some(vectors)
+    }
+
+    /// The coordinates `x^i = self . a^i` of a vector of the subspace of a frame, such that
+    /// `self == x^1 a_1 + x^2 a_2 + ...`.
+    pub fn coordinates(&self, frame: &[Self]) -> Option<Vec<T>> {
+        let reciprocal = Self::reciprocal_frame(frame)?;
+        Some(reciprocal.iter().map(|r| self.inner_product(r)).collect())
+    }
+
+    /// The vector `x^1 a_1 + x^2 a_2 + ...` with coordinates `x^i` in a frame.
+    pub fn from_coordinates(coordinates: &[T], frame: &[Self]) -> Self {
+        coordinates.iter().zip(frame).fold(Self::zero(), |v, (x, a)| v + a.scale(x))
+    }
+}
+
+/// `E^-1 = E~ / (E * E~)` for a blade, `None` if it squares to zero.
+fn blade_inverse<T, const C: Clifford>(blade: &Multivector<T, C>) -> Option<Multivector<T, C>> where
+T: Real,
+[(); C.size()]: Sized,
+{
+    let square = blade.norm_squared();
+    if square.abs() <= T::epsilon() * blade.euclidean_norm() * blade.euclidean_norm() {
+        return None;
+    }
+    Some(blade.reverse().scale(&(T::one() / square)))
+}
@@ -0,0 +1,61 @@
+use crate::{Multivector, Sta, Vga};
+use crate::multivector::{Clifford, One, Zero};
+use crate::tests::{close, same};
+
+fn dual_basis<const C: Clifford>(frame: &[Multivector<f64, C>], reciprocal: &[Multivector<f64, C>]) -> bool where
+[(); C.size()]: Sized,
+{
+    reciprocal.len() == frame.len() && reciprocal.iter().enumerate().all(|(i, r)| {
+        frame.iter().enumerate().all(|(j, a)| close(r.inner_product(a), if i == j { 1.0 } else { 0.0 }))
+    })
+}
+
+#[test]
+fn reciprocal_frames() {
+    type V = Vga<f64, 3>;
+    let frame = [V::from_vector([1.0, 0.0, 0.0]), V::from_vector([1.0, 2.0, 0.0]), V::from_vector([0.5, -1.0, 3.0])];
+    assert!(dual_basis(&frame, &V::reciprocal_frame(&frame).unwrap()));
+
+    // Two vectors of a plane, whose reciprocals stay in the plane.
+    let plane = &frame[1..];
+    let reciprocal = V::reciprocal_frame(plane).unwrap();
+    assert!(dual_basis(plane, &reciprocal));
+    let blade = plane[0].outer_product(&plane[1]);
+    assert!(reciprocal.iter().all(|r| same(&r.outer_product(&blade), &V::zero())));
+
+    let events = [Sta::<f64>::event(1.0, 0.5, 0.0, 0.0), Sta::event(0.0, 1.0, 1.0, 0.0), Sta::event(2.0, 0.0, 1.0, 1.0), Sta::event(0.0, 0.0, 0.0, 3.0)];
+    assert!(dual_basis(&events, &Sta::reciprocal_frame(&events).unwrap()));
+
+    assert!(V::reciprocal_frame(&[frame[0].clone(), frame[0].scale(&2.0)]).is_none());
+    let null = [Sta::<f64>::event(1.0, 1.0, 0.0, 0.0)];
+    assert!(Sta::reciprocal_frame(&null).is_none());
+}
+
+#[test]
+fn gram_schmidt() {
+    type V = Vga<f64, 3>;
+    let frame = [V::from_vector([1.0, 1.0, 0.0]), V::from_vector([1.0, 2.0, 0.0]), V::from_vector([0.5, -1.0, 3.0])];
+    let orthonormal = V::gram_schmidt(&frame).unwrap();
+    assert!(dual_basis(&orthonormal, &orthonormal));
+    let mut blade = (V::one(), V::one());
+    for (a, u) in frame.iter().zip(&orthonormal) {
+        blade = (blade.0.outer_product(a), blade.1.outer_product(u));
+        let ratio = blade.0.inner_product(&blade.1.reverse());
+        assert!(ratio > 0.0 && same(&blade.0, &blade.1.scale(&ratio)));
+    }
+
+    let events = [Sta::<f64>::event(2.0, 1.0, 0.0, 0.0), Sta::event(0.0, 1.0, 1.0, 0.0)];
+    let orthonormal = Sta::gram_schmidt(&events).unwrap();
+    assert!(close(orthonormal[0].interval(), 1.0) && close(orthonormal[1].interval(), -1.0));
+    assert!(close(orthonormal[0].inner_product(&orthonormal[1]), 0.0));
+}
+
+#[test]
+fn coordinates() {
+    type V = Vga<f64, 3>;
+    let frame = [V::from_vector([1.0, 0.0, 0.0]), V::from_vector([1.0, 2.0, 0.0]), V::from_vector([0.5, -1.0, 3.0])];
+    let x = V::from_vector([2.0, -1.0, 4.0]);
+    let coordinates = x.coordinates(&frame).unwrap();
+    assert!(same(&V::from_coordinates(&coordinates, &frame), &x));
+    assert!(close(coordinates[2], 4.0 / 3.0));
+}
@@ -0,0 +1,206 @@
+use crate::multivector::{Clifford, Multivector, One, Ring, Zero};
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[cfg(test)]
+mod tests;
+
+
+/// Floating point types whose results can be rounded away from the exact value.
+pub trait Outward {
+    fn next_down(&self) -> Self;
+    fn next_up(&self) -> Self;
+}
+
+impl Outward for f64 {
+    fn next_down(&self) -> Self {
+        f64::next_down(*self)
+    }
+    fn next_up(&self) -> Self {
+        f64::next_up(*self)
+    }
+}
+
+impl Outward for f32 {
+    fn next_down(&self) -> Self {
+        f32::next_down(*self)
+    }
+    fn next_up(&self) -> Self {
+        f32::next_up(*self)
+    }
+}
+
+/// A closed interval `[lo, hi]` guaranteed to contain the exact result of the operations applied to it.
+///
+/// Every operation rounds its bounds outward by one unit in the last place, which covers the rounding
+/// error of a single floating point operation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T> Interval<T> where
+T: Clone + PartialOrd,
+{
+    pub fn new(lo: T, hi: T) -> Self {
+        assert!(lo <= hi, "interval bounds out of order");
+        Self { lo, hi }
+    }
+
+    pub fn lo(&self) -> T {
+        self.lo.clone()
+    }
+
+    pub fn hi(&self) -> T {
+        self.hi.clone()
+    }
+
+    pub fn contains(&self, x: &T) -> bool {
+        self.lo <= *x && *x <= self.hi
+    }
+
+    /// The sign of every value in the interval, `None` if the interval contains zero.
+    pub fn sign(&self) -> Option<Ordering> where
+    T: Zero,
+    {
+        if self.lo > T::zero() {
+            Some(Ordering::Greater)
+        } else if self.hi < T::zero() {
+            Some(Ordering::Less)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Interval<T> where
+T: Outward,
+{
+    fn outward(lo: T, hi: T) -> Self {
+        Self {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+}
+
+impl<T> From<T> for Interval<T> where
+T: Clone,
+{
+    fn from(x: T) -> Self {
+        Self {
+            lo: x.clone(),
+            hi: x,
+        }
+    }
+}
+
+impl<T> Zero for Interval<T> where
+T: Clone + Zero,
+{
+    fn zero() -> Self {
+        Self::from(T::zero())
+    }
+}
+
+impl<T> One for Interval<T> where
+T: Clone + One,
+{
+    fn one() -> Self {
+        Self::from(T::one())
+    }
+}
+
+impl<T> Add for Interval<T> where
+T: Ring + Outward,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::outward(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl<T> Sub for Interval<T> where
+T: Ring + Outward,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::outward(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+impl<T> Mul for Interval<T> where
+T: Ring + Outward + PartialOrd,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        let products = [
+            self.lo.clone() * other.lo.clone(),
+            self.lo * other.hi.clone(),
+            self.hi.clone() * other.lo,
+            self.hi * other.hi,
+        ];
+        let mut lo = products[0].clone();
+        let mut hi = products[0].clone();
+        for x in &products[1..] {
+            if *x < lo {
+                lo = x.clone();
+            }
+            if *x > hi {
+                hi = x.clone();
+            }
+        }
+        Self::outward(lo, hi)
+    }
+}
+
+impl<T> Neg for Interval<T> where
+T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            lo: self.hi.neg(),
+            hi: self.lo.neg(),
+        }
+    }
+}
+
+impl<T> AddAssign for Interval<T> where
+T: Ring + Outward,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = self.clone() + other;
+    }
+}
+
+impl<T> SubAssign for Interval<T> where
+T: Ring + Outward,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.clone() - other;
+    }
+}
+
+impl<T, const C: Clifford> Multivector<Interval<T>, C> where
+T: Ring + Outward + PartialOrd,
+[(); C.size()]: Sized,
+{
+    /// The certified sign of the pseudoscalar part of `self ^ other`, `None` if it may vanish.
+    ///
+    /// In PGA this tells which side of a plane (or line in 2D) a point lies on, and for two lines in 3D
+    /// the sense in which they pass each other; `None` means the point may be on the plane or the lines
+    /// may intersect.
+    pub fn orientation(&self, other: &Self) -> Option<Ordering> {
+        self.outer_product(other).coefficient(C.pseudoscalar_bits()).sign()
+    }
+
+    /// The certified sign of the scalar product of `self` and `other`, `None` if it may vanish.
+    ///
+    /// In CGA, the scalar product of a point with a dual sphere or plane is positive inside, negative
+    /// outside and zero on the surface.
+    pub fn inner_sign(&self, other: &Self) -> Option<Ordering> {
+        self.inner_product(other).sign()
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{Cga, Interval, Multivector, Pga};
+use crate::multivector::{Clifford, cga, pga};
+use core::cmp::Ordering;
+
+const PGA2: Clifford = pga(2);
+/// `Cl(4, 1)`, the conformal model of 3D space.
+const CGA4: Clifford = cga(4);
+
+fn interval(x: f64) -> Interval<f64> {
+    Interval::from(x)
+}
+
+#[test]
+fn enclosure() {
+    let x = interval(0.1) + interval(0.2);
+    assert!(x.contains(&0.3));
+    assert!(x.contains(&(0.1 + 0.2)));
+    assert_eq!((interval(0.1) * interval(3.0) - interval(0.3)).sign(), None);
+    assert_eq!((interval(2.0) * interval(-3.0)).sign(), Some(Ordering::Less));
+    assert_eq!((-interval(2.0)).hi(), -2.0);
+}
+
+/// The point `(x, y)` as the dual of `x e1 + y e2 + e0`, so that `line ^ point` is `(ax + by + c) I`.
+fn point(x: Interval<f64>, y: Interval<f64>) -> Pga<Interval<f64>, 2> {
+    Multivector::<Interval<f64>, PGA2>::from_vector([x, y, interval(1.0)]).dual()
+}
+
+#[test]
+fn certified_side_of_line() {
+    let diagonal = Multivector::<Interval<f64>, PGA2>::from_vector([interval(1.0), interval(-1.0), interval(0.0)]);
+    let ambiguous = point(interval(0.1) + interval(0.2), interval(0.3));
+    assert_eq!(diagonal.orientation(&ambiguous), None);
+
+    let naive = &Multivector::<f64, PGA2>::from_vector([1.0, -1.0, 0.0]) ^ &Multivector::from_vector([0.1 + 0.2, 0.3, 1.0]).dual();
+    assert!(naive[PGA2.size() - 1] != 0.0);
+
+    let above = point(interval(0.25), interval(0.5));
+    let below = point(interval(0.5), interval(0.25));
+    assert_eq!(diagonal.orientation(&above), Some(Ordering::Less));
+    assert_eq!(diagonal.orientation(&below), Some(Ordering::Greater));
+}
+
+#[test]
+fn certified_inside_sphere() {
+    let half = interval(0.5);
+    let origin = (Multivector::<Interval<f64>, CGA4>::basis(0b10000) - Multivector::basis(0b01000)).scale(&half);
+    let infinity = Multivector::<Interval<f64>, CGA4>::basis(0b10000) + Multivector::basis(0b01000);
+    let point = |x: f64, y: f64, z: f64| -> Cga<Interval<f64>, 4> {
+        let v = Multivector::from_vector([interval(x), interval(y), interval(z)]);
+        let square = v.inner_product(&v);
+        v + origin.clone() + infinity.scale(&(half * square))
+    };
+    let sphere = origin.clone() - infinity.scale(&half);
+
+    assert_eq!(point(0.5, 0.0, 0.0).inner_sign(&sphere), Some(Ordering::Greater));
+    assert_eq!(point(2.0, 0.0, 0.0).inner_sign(&sphere), Some(Ordering::Less));
+    assert_eq!(point(0.6, 0.8, 0.0).inner_sign(&sphere), None);
+}
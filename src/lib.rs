@@ -1,16 +1,57 @@
 #![allow(incomplete_features)]
 #![feature(const_for, adt_const_params, generic_const_exprs, iter_zip, maybe_uninit_uninit_array)]
 
+#[cfg(test)]
+mod tests;
+
 mod multivector;
-pub use multivector::{Clifford, Multivector, Float, One, Zero};
-use multivector::{STA, vga, cga, pga};
+mod dual;
+mod symbolic;
+mod interval;
+mod complex;
+mod pga2d;
+mod pga3d;
+mod motor;
+mod spline;
+mod matrix;
+mod euler;
+mod rotor;
+mod cga3d;
+mod conformal;
+mod sta;
+mod form;
+mod outermorphism;
+mod representation;
+mod blade;
+mod decomposition;
+mod frame;
+mod registration;
+mod average;
+pub use multivector::{Clifford, Multivector, Float, One, Real, Ring, Zero};
+use multivector::{QUATERNION, vga, cga, spacetime, pga};
+pub use dual::jacobian;
+pub use symbolic::{Expr, Symbol, emit_rust};
+pub use interval::{Interval, Outward};
+pub use complex::ComplexConjugate;
+pub use motor::Motor;
+pub use spline::{Bezier, BSpline};
+pub use euler::{EulerAxes, EulerFrame};
+pub use form::Form;
+pub use outermorphism::Outermorphism;
+pub use representation::{Field, Representation};
 
 
 pub type Vga<T, const D: usize> = Multivector<T, { vga(D) }>;
 pub type Cga<T, const D: usize> = Multivector<T, { cga(D) }>;
 pub type Pga<T, const D: usize> = Multivector<T, { pga(D) }>;
-pub type Sta<T> = Multivector<T, STA>;
+/// The spacetime algebra in the `+---` convention, or `-+++` if `MOSTLY_PLUS`, with the time vector first
+/// or last.
+pub type Spacetime<T, const MOSTLY_PLUS: bool, const TIME_FIRST: bool> = Multivector<T, { spacetime(MOSTLY_PLUS, TIME_FIRST) }>;
+pub type Sta<T> = Spacetime<T, false, true>;
 pub type Hyperbolic<T> = Vga<T, 0>;
 pub type Complex<T> = Cga<T, 0>;
+/// Dual numbers `a + b e0` with `e0 * e0 = 0`, usable as coefficients for forward-mode differentiation.
 pub type Dual<T> = Pga<T, 0>;
-pub type Quaternion<T> = Cga<T, 1>;
+pub type Quaternion<T> = Multivector<T, QUATERNION>;
+/// Rotations of `D`-dimensional space, the even multivectors of `Vga<T, D>`.
+pub type Rotor<T, const D: usize> = Vga<T, D>;
@@ -0,0 +1,133 @@
+use crate::{Motor, Vga};
+use crate::motor::{quaternion_to_rotor, rotor_to_quaternion};
+use crate::multivector::{Clifford, Multivector, Real, Ring};
+use core::ops::Div;
+
+#[cfg(test)]
+mod tests;
+
+
+/// Matrices are row-major and act on column vectors, `p' = m * p`.
+impl<T> Vga<T, 3> where
+T: Real,
+{
+    /// The rotation matrix of a rotor, normalizing it first.
+    pub fn to_rotation_matrix(&self) -> [[T; 3]; 3] {
+        let q = rotor_to_quaternion(self);
+        let n = q.iter().fold(T::zero(), |n, x| n + x.clone() * x.clone()).sqrt();
+        let [w, x, y, z] = q.map(|c| c / n.clone());
+        let two = T::from_f64(2.0);
+        let one = T::one();
+        [
+            [
+                one.clone() - two.clone() * (y.clone() * y.clone() + z.clone() * z.clone()),
+                two.clone() * (x.clone() * y.clone() - w.clone() * z.clone()),
+                two.clone() * (x.clone() * z.clone() + w.clone() * y.clone()),
+            ],
+            [
+                two.clone() * (x.clone() * y.clone() + w.clone() * z.clone()),
+                one.clone() - two.clone() * (x.clone() * x.clone() + z.clone() * z.clone()),
+                two.clone() * (y.clone() * z.clone() - w.clone() * x.clone()),
+            ],
+            [
+                two.clone() * (x.clone() * z.clone() - w.clone() * y.clone()),
+                two.clone() * (y.clone() * z.clone() + w.clone() * x.clone()),
+                one - two * (x.clone() * x + y.clone() * y),
+            ],
+        ]
+    }
+
+    /// The unit rotor of a rotation matrix, with a non-negative scalar part.
+    ///
+    /// Following Shepperd, the quaternion component of largest magnitude is found first from the trace and
+    /// diagonal, and the others divided by it, which stays accurate for rotations near 180 degrees where the
+    /// trace alone loses the axis.
+    pub fn from_rotation_matrix(m: &[[T; 3]; 3]) -> Self {
+        let quarter = T::from_f64(0.25);
+        let one = T::one();
+        let [m00, m11, m22] = [m[0][0].clone(), m[1][1].clone(), m[2][2].clone()];
+        let trace = m00.clone() + m11.clone() + m22.clone();
+        let q = if trace >= m00 && trace >= m11 && trace >= m22 {
+            let w = (one + trace).sqrt() * T::from_f64(0.5);
+            let k = quarter / w.clone();
+            [
+                w,
+                (m[2][1].clone() - m[1][2].clone()) * k.clone(),
+                (m[0][2].clone() - m[2][0].clone()) * k.clone(),
+                (m[1][0].clone() - m[0][1].clone()) * k,
+            ]
+        } else if m00 >= m11 && m00 >= m22 {
+            let x = (one + m00 - m11 - m22).sqrt() * T::from_f64(0.5);
+            let k = quarter / x.clone();
+            [
+                (m[2][1].clone() - m[1][2].clone()) * k.clone(),
+                x,
+                (m[0][1].clone() + m[1][0].clone()) * k.clone(),
+                (m[0][2].clone() + m[2][0].clone()) * k,
+            ]
+        } else if m11 >= m22 {
+            let y = (one - m00 + m11 - m22).sqrt() * T::from_f64(0.5);
+            let k = quarter / y.clone();
+            [
+                (m[0][2].clone() - m[2][0].clone()) * k.clone(),
+                (m[0][1].clone() + m[1][0].clone()) * k.clone(),
+                y,
+                (m[1][2].clone() + m[2][1].clone()) * k,
+            ]
+        } else {
+            let z = (one - m00 - m11 + m22).sqrt() * T::from_f64(0.5);
+            let k = quarter / z.clone();
+            [
+                (m[1][0].clone() - m[0][1].clone()) * k.clone(),
+                (m[0][2].clone() + m[2][0].clone()) * k.clone(),
+                (m[1][2].clone() + m[2][1].clone()) * k,
+                z,
+            ]
+        };
+        let q = if q[0] < T::zero() { q.map(|x| x.neg()) } else { q };
+        quaternion_to_rotor(q).normalized()
+    }
+}
+
+impl<T> Motor<T> where
+T: Real,
+{
+    /// The homogeneous matrix of a motor, with the rotation in the upper left and the translation in the
+    /// last column.
+    pub fn to_matrix(&self) -> [[T; 4]; 4] {
+        let (rotation, translation) = self.to_rotation_translation();
+        let r = rotation.to_rotation_matrix();
+        core::array::from_fn(|i| core::array::from_fn(|j| match (i, j) {
+            (3, 3) => T::one(),
+            (3, _) => T::zero(),
+            (_, 3) => translation[i].clone(),
+            _ => r[i][j].clone(),
+        }))
+    }
+
+    /// The motor of a homogeneous matrix whose upper left block is a rotation, ignoring its last row.
+    pub fn from_matrix(m: &[[T; 4]; 4]) -> Self {
+        let r = core::array::from_fn(|i| core::array::from_fn(|j| m[i][j].clone()));
+        let translation = core::array::from_fn(|i| m[i][3].clone());
+        Self::from_rotation_translation(&Vga::from_rotation_matrix(&r), translation)
+    }
+}
+
+impl<T, const C: Clifford> Multivector<T, C> where
+[(); C.size()]: Sized,
+{
+    /// The matrix of the linear map `x -> self.involute() * x * self.inverse()` of a versor on vectors,
+    /// whose outermorphism is the action of the versor on every grade.
+    ///
+    /// Rows and columns follow the basis vectors in the order of `vector_part`; odd versors give the
+    /// reflections they stand for rather than their negatives.
+    pub fn versor_matrix(&self) -> Vec<Vec<T>> where
+    T: Ring + Div<Output = T>,
+    {
+        let (v, inverse) = (self.involute(), self.inverse());
+        let columns: Vec<Vec<T>> = (0..C.dim())
+            .map(|i| v.geometric_product(&Self::basis(1 << i)).geometric_product(&inverse).vector_part())
+            .collect();
+        (0..C.dim()).map(|i| columns.iter().map(|column| column[i].clone()).collect()).collect()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Motor, Pga, Sta, Vga};
+use crate::tests::close;
+use core::f64::consts::{FRAC_PI_2, PI};
+
+type V = Vga<f64, 3>;
+type P = Pga<f64, 3>;
+
+fn same_matrix<const N: usize>(lhs: &[[f64; N]; N], rhs: &[[f64; N]; N]) -> bool {
+    (0..N).all(|i| (0..N).all(|j| close(lhs[i][j], rhs[i][j])))
+}
+
+fn rotor(axis: [f64; 3], angle: f64) -> V {
+    let (rotation, _) = Motor::from_axis_angle(axis, angle).to_rotation_translation();
+    rotation
+}
+
+fn apply(m: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    core::array::from_fn(|i| (0..3).map(|j| m[i][j] * v[j]).sum())
+}
+
+#[test]
+fn rotation_matrices() {
+    let quarter = rotor([0.0, 0.0, 1.0], FRAC_PI_2).to_rotation_matrix();
+    assert!(same_matrix(&quarter, &[[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]));
+
+    let r = rotor([1.0, -2.0, 0.5], 1.1);
+    let v = [0.3, -1.0, 2.0];
+    let rotated = r.sandwich(&V::from_vector(v)).vector_part();
+    let m = r.to_rotation_matrix();
+    assert!(apply(&m, &v).iter().zip(&rotated).all(|(a, b)| close(*a, *b)));
+    assert!((0..8).all(|i| close(V::from_rotation_matrix(&m)[i], r[i])));
+}
+
+#[test]
+fn rotation_matrices_near_half_turn() {
+    for angle in [PI, PI - 1e-7, PI + 1e-7, 3.0, 0.0, 1e-8] {
+        for axis in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 1.0, 0.0], [-1.0, 2.0, 3.0]] {
+            let m = rotor(axis, angle).to_rotation_matrix();
+            let back = V::from_rotation_matrix(&m);
+            assert!(close(back.norm(), 1.0));
+            assert!(same_matrix(&back.to_rotation_matrix(), &m));
+        }
+    }
+}
+
+#[test]
+fn homogeneous_matrices() {
+    let line = P::point(1.0, 2.0, 0.0).line_through(&P::point(0.0, 1.0, 3.0));
+    let motor = Motor::from_screw(&line, 2.5, 0.7);
+    let m = motor.to_matrix();
+    let (x, y, z) = motor.transform_point(&P::point(0.5, -1.0, 2.0)).to_point().unwrap();
+    let p: [f64; 4] = core::array::from_fn(|i| m[i][0] * 0.5 - m[i][1] + m[i][2] * 2.0 + m[i][3]);
+    assert!(close(p[0], x) && close(p[1], y) && close(p[2], z) && close(p[3], 1.0));
+    assert!(same_matrix(&Motor::from_matrix(&m).to_matrix(), &m));
+}
+
+#[test]
+fn versor_matrices() {
+    let r = rotor([1.0, -2.0, 0.5], 1.1);
+    let m = r.versor_matrix();
+    let expected = r.to_rotation_matrix();
+    assert!((0..3).all(|i| (0..3).all(|j| close(m[i][j], expected[i][j]))));
+
+    let mirror = V::from_vector([1.0, 0.0, 0.0]).versor_matrix();
+    assert_eq!(mirror, vec![vec![-1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0]]);
+
+    // A boost along e1 preserves the metric diag(1, -1, -1, -1) of spacetime.
+    let boost = (Sta::<f64>::basis(0b0011).scale(&0.4)).exp();
+    let m = boost.versor_matrix();
+    let metric = [1.0, -1.0, -1.0, -1.0];
+    for i in 0..4 {
+        for j in 0..4 {
+            let g: f64 = (0..4).map(|k| m[k][i] * metric[k] * m[k][j]).sum();
+            assert!(close(g, if i == j { metric[i] } else { 0.0 }));
+        }
+    }
+
+    let motor = Motor::from_screw(&P::point(0.0, 1.0, 0.0).line_through(&P::point(1.0, 1.0, 1.0)), 0.9, 0.3);
+    let m = P::from(motor).versor_matrix();
+    let (a, b, c, d) = motor.transform_plane(&P::plane(1.0, 2.0, -1.0, 4.0)).to_plane();
+    let plane = [1.0, 2.0, -1.0, 4.0];
+    let image: Vec<f64> = (0..4).map(|i| (0..4).map(|j| m[i][j] * plane[j]).sum()).collect();
+    assert!(close(image[0], a) && close(image[1], b) && close(image[2], c) && close(image[3], d));
+}
@@ -0,0 +1,367 @@
+use crate::{Pga, Vga};
+use crate::multivector::{One, Real, Ring, Zero};
+use crate::pga3d::{cross, dot};
+use core::ops::{Add, Mul};
+
+#[cfg(test)]
+mod tests;
+
+
+/// The blades of a motor, in the order of its coefficients.
+const BLADES: [usize; 8] = [0, 0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100, 0b1111];
+
+pub(crate) fn quaternion_product<T: Ring>(a: &[T; 4], b: &[T; 4]) -> [T; 4] {
+    let [a0, a1, a2, a3] = a.clone();
+    let [b0, b1, b2, b3] = b.clone();
+    [
+        a0.clone() * b0.clone() - a1.clone() * b1.clone() - a2.clone() * b2.clone() - a3.clone() * b3.clone(),
+        a0.clone() * b1.clone() + a1.clone() * b0.clone() + a2.clone() * b3.clone() - a3.clone() * b2.clone(),
+        a0.clone() * b2.clone() - a1.clone() * b3.clone() + a2.clone() * b0.clone() + a3.clone() * b1.clone(),
+        a0 * b3 + a1 * b2 - a2 * b1 + a3 * b0,
+    ]
+}
+
+fn quaternion_conjugate<T: Ring>(q: &[T; 4]) -> [T; 4] {
+    let [w, x, y, z] = q.clone();
+    [w, x.neg(), y.neg(), z.neg()]
+}
+
+/// Rotate `v` by the unit quaternion `q`.
+fn quaternion_rotate<T: Real>(q: &[T; 4], v: &[T; 3]) -> [T; 3] {
+    let [w, x, y, z] = q.clone();
+    let u = [x, y, z];
+    let two = T::from_f64(2.0);
+    let t = cross(&u, v).map(|c| c * two.clone());
+    let s = cross(&u, &t);
+    core::array::from_fn(|i| v[i].clone() + w.clone() * t[i].clone() + s[i].clone())
+}
+
+/// The quaternion `(w, x, y, z)` of a rotor of 3D VGA, with `i = -e23`, `j = e13` and `k = -e12`.
+pub(crate) fn rotor_to_quaternion<T: Ring>(rotor: &Vga<T, 3>) -> [T; 4] {
+    [
+        rotor.coefficient(0).clone(),
+        rotor.coefficient(0b110).clone().neg(),
+        rotor.coefficient(0b101).clone(),
+        rotor.coefficient(0b011).clone().neg(),
+    ]
+}
+
+pub(crate) fn quaternion_to_rotor<T: Ring>(q: [T; 4]) -> Vga<T, 3> {
+    let [w, x, y, z] = q;
+    let mut rotor = Vga::zero();
+    *rotor.coefficient_mut(0) = w;
+    *rotor.coefficient_mut(0b110) = x.neg();
+    *rotor.coefficient_mut(0b101) = y;
+    *rotor.coefficient_mut(0b011) = z.neg();
+    rotor
+}
+
+/// A rigid motion of space, the even part of 3D PGA with coefficients on `1, e12, e13, e23, e10, e20, e30, e0123`.
+///
+/// Writing `I = e0123`, a motor is `R + I D` for two rotor-like parts `R` and `D`, which makes it a dual
+/// quaternion: the real part `(1, -e23, e13, -e12)` and the dual part `(e0123, e10, e20, e30)` multiply as
+/// quaternions. Composition and the transforms below are written in those terms instead of the full
+/// geometric product.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Motor<T> {
+    data: [T; 8],
+}
+
+impl<T> From<[T; 8]> for Motor<T> {
+    fn from(data: [T; 8]) -> Self {
+        Self { data }
+    }
+}
+
+impl<T> From<Motor<T>> for Pga<T, 3> where
+T: Clone + Zero,
+{
+    fn from(motor: Motor<T>) -> Self {
+        let mut x = Self::zero();
+        for (bits, v) in BLADES.iter().zip(motor.data) {
+            *x.coefficient_mut(*bits) = v;
+        }
+        x
+    }
+}
+
+impl<T> Motor<T> where
+T: Ring,
+{
+    pub fn identity() -> Self {
+        Self::from_quaternions([T::one(), T::zero(), T::zero(), T::zero()], [T::zero(), T::zero(), T::zero(), T::zero()])
+    }
+
+    /// The even part of a multivector, dropping its odd grades.
+    pub fn from_even(x: &Pga<T, 3>) -> Self {
+        Self::from(BLADES.map(|bits| x.coefficient(bits).clone()))
+    }
+
+    fn from_quaternions(real: [T; 4], dual: [T; 4]) -> Self {
+        let [r0, r1, r2, r3] = real;
+        let [d0, d1, d2, d3] = dual;
+        Self::from([r0, r3.neg(), r2, r1.neg(), d1, d2, d3, d0])
+    }
+
+    fn real(&self) -> [T; 4] {
+        let d = &self.data;
+        [d[0].clone(), d[3].clone().neg(), d[2].clone(), d[1].clone().neg()]
+    }
+
+    fn dual(&self) -> [T; 4] {
+        let d = &self.data;
+        [d[7].clone(), d[4].clone(), d[5].clone(), d[6].clone()]
+    }
+
+    pub fn coefficients(&self) -> &[T; 8] {
+        &self.data
+    }
+
+    /// The reverse, which is the inverse of a normalized motor.
+    pub fn reverse(&self) -> Self {
+        Self::from_quaternions(quaternion_conjugate(&self.real()), quaternion_conjugate(&self.dual()))
+    }
+
+    /// `self * self.reverse()` as the dual number `(a, b)` standing for `a + b e0123`.
+    fn norm_squared(&self) -> (T, T) {
+        let (r, d) = (self.real(), self.dual());
+        let mut a = T::zero();
+        let mut b = T::zero();
+        for i in 0..4 {
+            a += r[i].clone() * r[i].clone();
+            b += r[i].clone() * d[i].clone();
+        }
+        (a, b.clone() + b)
+    }
+
+    /// Multiply by the dual number `a + b e0123`, which commutes with every motor.
+    fn scale(&self, a: &T, b: &T) -> Self {
+        let (r, d) = (self.real(), self.dual());
+        Self::from_quaternions(
+            r.clone().map(|x| x * a.clone()),
+            core::array::from_fn(|i| d[i].clone() * a.clone() + r[i].clone() * b.clone()),
+        )
+    }
+}
+
+impl<T> Motor<T> where
+T: Real,
+{
+    pub fn from_translation(x: T, y: T, z: T) -> Self {
+        let half = T::from_f64(0.5);
+        Self::from_quaternions(
+            [T::one(), T::zero(), T::zero(), T::zero()],
+            [T::zero(), x * half.clone(), y * half.clone(), z * half],
+        )
+    }
+
+    /// The motor applying the rotor `rotation` of 3D VGA about the origin, then translating by `translation`.
+    pub fn from_rotation_translation(rotation: &Vga<T, 3>, translation: [T; 3]) -> Self {
+        let rotation = Self::from(BLADES.map(|bits| {
+            if bits < 0b1000 {
+                rotation.coefficient(bits).clone()
+            } else {
+                T::zero()
+            }
+        }));
+        let [x, y, z] = translation;
+        Self::from_translation(x, y, z) * rotation
+    }
+
+    /// The rotation by `angle` about an axis through the origin, counterclockwise when looking against it.
+    pub fn from_axis_angle(axis: [T; 3], angle: T) -> Self {
+        let half = angle * T::from_f64(0.5);
+        let s = half.sin() / dot(&axis, &axis).sqrt();
+        let [x, y, z] = axis.map(|c| c * s.clone());
+        Self::from_quaternions([half.cos(), x, y, z], [T::zero(), T::zero(), T::zero(), T::zero()])
+    }
+
+    /// The screw motion turning by `angle` about `line` while advancing `pitch * angle` along its direction.
+    pub fn from_screw(line: &Pga<T, 3>, angle: T, pitch: T) -> Self {
+        let (d, _) = line.plucker();
+        let s = pitch * angle.clone() / dot(&d, &d).sqrt();
+        let [x, y, z] = d.map(|c| c * s.clone());
+        Self::from_translation(x, y, z) * Self::from_even(&Pga::rotor(line, angle))
+    }
+
+    /// Split a motor into a rotor of 3D VGA about the origin followed by a translation, the inverse of
+    /// `from_rotation_translation`.
+    pub fn to_rotation_translation(&self) -> (Vga<T, 3>, [T; 3]) {
+        let m = self.normalized();
+        let mut rotation = Vga::zero();
+        for (bits, v) in BLADES.iter().zip(&m.data).take(4) {
+            *rotation.coefficient_mut(*bits) = v.clone();
+        }
+        (rotation, m.translation())
+    }
+
+    /// The translation of a normalized motor, applied after its rotation.
+    fn translation(&self) -> [T; 3] {
+        let t = quaternion_product(&self.dual(), &quaternion_conjugate(&self.real()));
+        let two = T::from_f64(2.0);
+        [t[1].clone() * two.clone(), t[2].clone() * two.clone(), t[3].clone() * two]
+    }
+
+    /// Scale to `self * self.reverse() == 1`, correcting the drift that accumulates over many compositions.
+    ///
+    /// Besides a unit rotation part, this restores the constraint between the rotation and translation
+    /// parts, so that the result is again an exact rigid motion.
+    pub fn normalized(&self) -> Self {
+        let (a, b) = self.norm_squared();
+        let alpha = T::one() / a.sqrt();
+        let beta = (b * alpha.clone() / (a.clone() + a)).neg();
+        self.scale(&alpha, &beta)
+    }
+
+    /// The inverse, equal to the reverse for normalized motors.
+    pub fn inverse(&self) -> Self {
+        let (a, b) = self.norm_squared();
+        let beta = (b / (a.clone() * a.clone())).neg();
+        self.reverse().scale(&(T::one() / a), &beta)
+    }
+
+    /// The motor `exp(bivector)`, in closed form.
+    ///
+    /// A line `L` with `L * L == -1` gives the rotation `exp(angle / 2 * L)` of `Pga::rotor`, and an ideal
+    /// line `(x e10 + y e20 + z e30) / 2` the translation by `(x, y, z)`.
+    pub fn exp(bivector: &Pga<T, 3>) -> Self {
+        let (u, v) = bivector.plucker();
+        let square = dot(&u, &u);
+        let uv = dot(&u, &v);
+        let theta = square.sqrt();
+        // sin(theta) / theta and (cos(theta) - sin(theta) / theta) / theta^2, by series near zero.
+        let (sinc, k) = if square < T::epsilon().sqrt() {
+            (
+                T::one() - square.clone() / T::from_f64(6.0),
+                square.clone() / T::from_f64(30.0) - T::one() / T::from_f64(3.0),
+            )
+        } else {
+            let sinc = theta.sin() / theta.clone();
+            (sinc.clone(), (theta.cos() - sinc) / square.clone())
+        };
+        let [u0, u1, u2] = u.clone().map(|x| x * sinc.clone());
+        let w = uv.clone() * k;
+        let [v0, v1, v2] = core::array::from_fn(|i| v[i].clone() * sinc.clone() + u[i].clone() * w.clone());
+        Self::from_quaternions([theta.cos(), u0, u1, u2], [(uv * sinc).neg(), v0, v1, v2])
+    }
+
+    /// The bivector `B` with `Motor::exp(&B) == self` up to scale, taking the shorter way round for rotations.
+    pub fn log(&self) -> Pga<T, 3> {
+        let mut m = self.normalized();
+        if m.data[0] < T::zero() {
+            m = Self::from(m.data.map(|x| x.neg()));
+        }
+        let (q, p) = (m.real(), m.dual());
+        let a = [q[1].clone(), q[2].clone(), q[3].clone()];
+        let square = dot(&a, &a);
+        let s = square.sqrt();
+        let theta = s.atan2(&q[0]);
+        // theta / sin(theta) and (1 - theta cos(theta) / sin(theta)) / sin(theta)^2, by series near zero.
+        let (ratio, f) = if square < T::epsilon().sqrt() {
+            (
+                T::one() + square.clone() / T::from_f64(6.0),
+                T::one() / T::from_f64(3.0) + square.clone() * T::from_f64(2.0) / T::from_f64(15.0),
+            )
+        } else {
+            let ratio = theta / s;
+            (ratio.clone(), (T::one() - ratio * q[0].clone()) / square.clone())
+        };
+        let w = p[0].clone() * f;
+        let u = a.clone().map(|x| x * ratio.clone());
+        let v = core::array::from_fn(|i| p[i + 1].clone() * ratio.clone() - a[i].clone() * w.clone());
+        Pga::from_plucker(u, v)
+    }
+
+    /// Screw-linear interpolation, moving from `self` at `t = 0` to `other` at `t = 1` with constant velocity
+    /// along the screw motion between them.
+    pub fn sclerp(&self, other: &Self, t: T) -> Self {
+        let step = (&self.reverse() * other).log();
+        self * &Self::exp(&step.scale(&t))
+    }
+
+    /// Apply to any element of 3D PGA with the sandwich product.
+    pub fn apply(&self, x: &Pga<T, 3>) -> Pga<T, 3> {
+        Pga::from(self.clone()).sandwich(x)
+    }
+
+    /// Move a point with a normalized motor, as `apply` but in a handful of operations.
+    pub fn transform_point(&self, point: &Pga<T, 3>) -> Pga<T, 3> {
+        let v = point.undual();
+        let [x, y, z, w] = [0b0001, 0b0010, 0b0100, 0b1000].map(|e| v.coefficient(e).clone());
+        let p = quaternion_rotate(&self.real(), &[x, y, z]);
+        let t = self.translation();
+        let [x, y, z] = core::array::from_fn(|i| p[i].clone() + w.clone() * t[i].clone());
+        Pga::from_vector([x, y, z, w]).dual()
+    }
+
+    /// Move a line with a normalized motor, as `apply` but in a handful of operations.
+    pub fn transform_line(&self, line: &Pga<T, 3>) -> Pga<T, 3> {
+        let (d, m) = line.plucker();
+        let q = self.real();
+        let d = quaternion_rotate(&q, &d);
+        let m = quaternion_rotate(&q, &m);
+        let s = cross(&self.translation(), &d);
+        Pga::from_plucker(d, core::array::from_fn(|i| m[i].clone() + s[i].clone()))
+    }
+
+    /// Move a plane with a normalized motor, as `apply` but in a handful of operations.
+    pub fn transform_plane(&self, plane: &Pga<T, 3>) -> Pga<T, 3> {
+        let (a, b, c, d) = plane.to_plane();
+        let n = quaternion_rotate(&self.real(), &[a, b, c]);
+        let d = d - dot(&n, &self.translation());
+        let [a, b, c] = n;
+        Pga::plane(a, b, c, d)
+    }
+}
+
+impl<T> Zero for Motor<T> where
+T: Zero,
+{
+    fn zero() -> Self {
+        Self::from(core::array::from_fn(|_| T::zero()))
+    }
+}
+
+impl<T> One for Motor<T> where
+T: Ring,
+{
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+/// Composition, applying `other` first and then `self`.
+impl<T> Mul<&Motor<T>> for &Motor<T> where
+T: Ring,
+{
+    type Output = Motor<T>;
+    fn mul(self, other: &Motor<T>) -> Self::Output {
+        let (r, d) = (self.real(), self.dual());
+        let (s, e) = (other.real(), other.dual());
+        let dr = quaternion_product(&d, &s);
+        let rd = quaternion_product(&r, &e);
+        Motor::from_quaternions(quaternion_product(&r, &s), core::array::from_fn(|i| rd[i].clone() + dr[i].clone()))
+    }
+}
+
+impl<T> Mul for Motor<T> where
+T: Ring,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        &self * &other
+    }
+}
+
+/// The sum of two motors, e.g. of the terms of a derivative, which is not itself a rigid motion.
+impl<T> Add for Motor<T> where
+T: Ring,
+{
+    type Output = Self;
+    fn add(mut self, other: Self) -> Self::Output {
+        for (x, y) in self.data.iter_mut().zip(other.data) {
+            *x += y;
+        }
+        self
+    }
+}
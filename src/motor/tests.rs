@@ -0,0 +1,94 @@
+use crate::{Motor, Pga, Vga};
+use crate::tests::{exact, same};
+use core::f64::consts::{FRAC_PI_2, PI};
+
+type P = Pga<f64, 3>;
+
+fn same_motor(lhs: &Motor<f64>, rhs: &Motor<f64>) -> bool {
+    lhs.coefficients().iter().zip(rhs.coefficients()).all(|(a, b)| exact(*a, *b))
+}
+
+fn at(p: &P, x: f64, y: f64, z: f64) -> bool {
+    let (u, v, w) = p.to_point().unwrap();
+    exact(u, x) && exact(v, y) && exact(w, z)
+}
+
+fn motors() -> Vec<Motor<f64>> {
+    let line = P::point(1.0, 2.0, -1.0).line_through(&P::point(0.0, 3.0, 1.0));
+    vec![
+        Motor::from_axis_angle([1.0, 2.0, 2.0], 0.7),
+        Motor::from_translation(3.0, -1.0, 0.5),
+        Motor::from_screw(&line, 2.1, 0.3),
+        Motor::from_screw(&line, -0.4, 0.0) * Motor::from_translation(0.0, 2.0, 0.0),
+    ]
+}
+
+#[test]
+fn constructors() {
+    let quarter = Motor::from_axis_angle([0.0, 0.0, 2.0], FRAC_PI_2);
+    let z_axis = P::point(0.0, 0.0, 0.0).line_through(&P::point(0.0, 0.0, 1.0));
+    assert!(same(&P::from(quarter), &P::rotor(&z_axis, FRAC_PI_2)));
+    assert!(same(&P::from(Motor::from_translation(1.0, -2.0, 3.0)), &P::translator(1.0, -2.0, 3.0)));
+    assert!(at(&quarter.apply(&P::point(1.0, 0.0, 2.0)), 0.0, 1.0, 2.0));
+
+    let axis = P::point(1.0, 0.0, 0.0).line_through(&P::point(1.0, 0.0, 1.0));
+    let screw = Motor::from_screw(&axis, PI, 0.5);
+    assert!(at(&screw.apply(&P::point(2.0, 0.0, 0.0)), 0.0, 0.0, PI * 0.5));
+    assert!(same_motor(&Motor::from_even(&P::from(screw)), &screw));
+}
+
+#[test]
+fn composition_and_inverse() {
+    for a in motors() {
+        for b in motors() {
+            assert!(same(&P::from(a * b), &(P::from(a) * P::from(b))));
+        }
+        assert!(same_motor(&(a * a.inverse()), &Motor::identity()));
+        assert!(same_motor(&a.inverse(), &a.reverse()));
+        let scaled = Motor::from(a.coefficients().map(|x| x * 3.0));
+        assert!(same_motor(&(scaled.inverse() * scaled), &Motor::identity()));
+    }
+}
+
+#[test]
+fn rotation_translation_round_trip() {
+    let rotor = Vga::<f64, 3>::from_vector([0.0, 0.6, 0.8]).geometric_product(&Vga::from_vector([1.0, 0.0, 0.0]));
+    let motor = Motor::from_rotation_translation(&rotor, [1.0, 2.0, 3.0]);
+    let (rotation, translation) = motor.to_rotation_translation();
+    assert!((0..8).all(|i| exact(rotation[i], rotor[i])));
+    assert!(exact(translation[0], 1.0) && exact(translation[1], 2.0) && exact(translation[2], 3.0));
+    assert!(at(&motor.apply(&P::point(0.0, 0.0, 0.0)), 1.0, 2.0, 3.0));
+    for m in motors() {
+        let (rotation, [x, y, z]) = m.to_rotation_translation();
+        assert!(same_motor(&Motor::from_rotation_translation(&rotation, [x, y, z]), &m));
+    }
+}
+
+#[test]
+fn normalization_corrects_drift() {
+    let step = Motor::from_screw(&P::point(1.0, 0.0, 0.0).line_through(&P::point(1.0, 1.0, 1.0)), 0.01, 0.2);
+    let mut drifted = Motor::identity();
+    for i in 0..1000 {
+        let noise = 1.0 + 1e-7 * ((i % 7) as f64 - 3.0);
+        drifted = Motor::from(drifted.coefficients().map(|x| x * noise)) * step;
+    }
+    let corrected = drifted.normalized();
+    assert!(same_motor(&(corrected * corrected.reverse()), &Motor::identity()));
+    let bent = Motor::from(core::array::from_fn(|i| drifted.coefficients()[i] + if i == 7 { 1e-3 } else { 0.0 }));
+    let corrected = bent.normalized();
+    assert!(same_motor(&(corrected * corrected.reverse()), &Motor::identity()));
+}
+
+#[test]
+fn fast_transforms() {
+    let point = P::point(0.5, -2.0, 3.0);
+    let line = point.line_through(&P::point(1.0, 1.0, 1.0));
+    let plane = P::plane(1.0, -2.0, 0.5, 4.0);
+    let direction = P::direction(1.0, 2.0, 3.0);
+    for m in motors() {
+        assert!(same(&m.transform_point(&point), &m.apply(&point)));
+        assert!(same(&m.transform_point(&direction), &m.apply(&direction)));
+        assert!(same(&m.transform_line(&line), &m.apply(&line)));
+        assert!(same(&m.transform_plane(&plane), &m.apply(&plane)));
+    }
+}
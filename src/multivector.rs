@@ -1,5 +1,8 @@
-use core::ops::{Add, Mul, Neg, AddAssign, SubAssign};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Neg, Not, Sub, AddAssign, SubAssign};
 use core::iter::{zip};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::Ratio;
 
 #[cfg(test)]
 mod tests;
@@ -60,27 +63,246 @@ impl Zero for f32 {
     }
 }
 
+macro_rules! impl_integer {
+    ($($t:ty)*) => {
+        $(
+            impl One for $t {
+                fn one() -> Self {
+                    1
+                }
+            }
+
+            impl Zero for $t {
+                fn zero() -> Self {
+                    0
+                }
+            }
+        )*
+    }
+}
+
+impl_integer!(i8 i16 i32 i64 i128 isize);
+
+impl One for BigInt {
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt::from(0)
+    }
+}
+
+impl<T> One for Ratio<T> where
+T: Clone + Integer,
+{
+    fn one() -> Self {
+        Ratio::from_integer(T::one())
+    }
+}
+
+impl<T> Zero for Ratio<T> where
+T: Clone + Integer,
+{
+    fn zero() -> Self {
+        Ratio::from_integer(T::zero())
+    }
+}
+
+/// Coefficients closed under the polynomial operations of the algebra.
+///
+/// Products, involutions, duals and the regressive product only ever add, subtract and multiply
+/// coefficients, so they are exact for integers and rationals.
+pub trait Ring: Clone + Zero + One + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self> + AddAssign + SubAssign {}
+
+impl<T> Ring for T where
+T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + AddAssign + SubAssign,
+{}
+
+/// Coefficients approximating the real numbers, required by norms and other floating-only operations.
+pub trait Real: Ring + Div<Output = Self> + PartialOrd + Float {
+    fn from_f64(x: f64) -> Self;
+    fn epsilon() -> Self;
+    fn sqrt(&self) -> Self;
+    fn abs(&self) -> Self;
+    fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn sinh(&self) -> Self;
+    fn cosh(&self) -> Self;
+    fn atan2(&self, other: &Self) -> Self;
+}
+
+impl Real for f64 {
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+    fn sqrt(&self) -> Self {
+        f64::sqrt(*self)
+    }
+    fn abs(&self) -> Self {
+        f64::abs(*self)
+    }
+    fn exp(&self) -> Self {
+        f64::exp(*self)
+    }
+    fn ln(&self) -> Self {
+        f64::ln(*self)
+    }
+    fn sin(&self) -> Self {
+        f64::sin(*self)
+    }
+    fn cos(&self) -> Self {
+        f64::cos(*self)
+    }
+    fn sinh(&self) -> Self {
+        f64::sinh(*self)
+    }
+    fn cosh(&self) -> Self {
+        f64::cosh(*self)
+    }
+    fn atan2(&self, other: &Self) -> Self {
+        f64::atan2(*self, *other)
+    }
+}
+
+impl Real for f32 {
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+    fn sqrt(&self) -> Self {
+        f32::sqrt(*self)
+    }
+    fn abs(&self) -> Self {
+        f32::abs(*self)
+    }
+    fn exp(&self) -> Self {
+        f32::exp(*self)
+    }
+    fn ln(&self) -> Self {
+        f32::ln(*self)
+    }
+    fn sin(&self) -> Self {
+        f32::sin(*self)
+    }
+    fn cos(&self) -> Self {
+        f32::cos(*self)
+    }
+    fn sinh(&self) -> Self {
+        f32::sinh(*self)
+    }
+    fn cosh(&self) -> Self {
+        f32::cosh(*self)
+    }
+    fn atan2(&self, other: &Self) -> Self {
+        f32::atan2(*self, *other)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Clifford {
-    positive: usize,
+    dim: usize,
+    /// The bits of the basis vectors squaring to `-1`.
     negative: usize,
-    zero: usize
+    /// The bits of the basis vectors squaring to `0`; all others square to `1`.
+    zero: usize,
+    /// Whether the blades of each grade are in lexicographic order of their indices, `e03` before `e12`, rather
+    /// than in order of their bit representations.
+    lexicographic: bool,
+    /// Whether the blades in the upper half of the layout are oriented as the complements of their mirror
+    /// images in the lower half, rather than as the product of their vectors in increasing order.
+    complementary: bool,
 }
 
 impl Clifford {
+    /// The algebra with `positive`, `negative` and `zero` basis vectors squaring to `1`, `-1` and `0`, in
+    /// that order.
+    pub const fn new(positive: usize, negative: usize, zero: usize) -> Clifford {
+        Clifford {
+            dim: positive + negative + zero,
+            negative: ((1 << negative) - 1) << positive,
+            zero: ((1 << zero) - 1) << (positive + negative),
+            lexicographic: false,
+            complementary: false,
+        }
+    }
+
+    /// The algebra whose basis vector `i` squares to `metric[i]`, one of `1`, `-1` and `0`, e.g.
+    /// `from_metric(&[0, 1, 1, 1])` for 3D PGA with `e0` first as in ganja.js.
+    pub const fn from_metric(metric: &[i8]) -> Clifford {
+        let mut negative = 0;
+        let mut zero = 0;
+        let mut i = 0;
+        while i < metric.len() {
+            match metric[i] {
+                1 => {}
+                -1 => negative |= 1 << i,
+                0 => zero |= 1 << i,
+                _ => panic!("basis vectors must square to 1, -1 or 0"),
+            }
+            i += 1;
+        }
+        Clifford {
+            dim: metric.len(),
+            negative,
+            zero,
+            lexicographic: false,
+            complementary: false,
+        }
+    }
+
+    /// The same algebra with the blades of each grade in lexicographic order of their indices, e.g. `e01, e02,
+    /// e03, e12, e13, e23`, as ganja.js lays them out.
+    pub const fn lexicographic(self) -> Clifford {
+        Clifford {
+            lexicographic: true,
+            ..self
+        }
+    }
+
+    /// The same algebra with each blade in the upper half of the layout oriented such that its mirror image in
+    /// the lower half times it is the pseudoscalar, e.g. `e31` rather than `e13` opposite `e02` in 3D PGA, as
+    /// ganja.js lays them out.
+    ///
+    /// `basis` and `coefficient` refer to the blades as laid out, so `basis(0b1010)` is `e31` in that case.
+    pub const fn complementary(self) -> Clifford {
+        Clifford {
+            complementary: true,
+            ..self
+        }
+    }
+
     pub const fn dim(self) -> usize {
-        self.positive + self.negative + self.zero
+        self.dim
     }
     pub const fn size(self) -> usize {
         1 << self.dim()
     }
 
     pub const fn negative_bits(self) -> usize {
-        (1 << self.negative) - 1 << self.positive
+        self.negative
     }
 
     pub const fn zero_bits(self) -> usize {
-        (1 << self.zero) - 1 << self.positive + self.negative
+        self.zero
+    }
+
+    pub const fn pseudoscalar_bits(self) -> usize {
+        self.size() - 1
+    }
+
+    /// Grade of the basis blade with bit representation `x`.
+    pub const fn grade(x: usize) -> usize {
+        usize::count_ones(x) as usize
     }
 
     const fn zero_by_form(self, x: usize) -> bool {
@@ -102,7 +324,76 @@ impl Clifford {
         flips % 2 != 0
     }
 
-    const fn bit_to_blade(self, x: usize) -> usize {
+    /// Sign of the geometric product of the basis blades `lhs` and `rhs`, `None` if it vanishes.
+    const fn flip_by_product(self, lhs: usize, rhs: usize) -> Option<bool> {
+        if self.zero_by_form(lhs & rhs) {
+            None
+        } else {
+            let flip = Clifford::flip_by_anticommutativity(lhs, rhs) != self.flip_by_form(lhs & rhs);
+            Some(flip ^ self.flip_by_layout(lhs) ^ self.flip_by_layout(rhs) ^ self.flip_by_layout(lhs ^ rhs))
+        }
+    }
+
+    /// Sign relating the blade laid out for `x` to the product of its vectors in increasing order.
+    pub(crate) const fn flip_by_layout(self, x: usize) -> bool {
+        if !self.complementary {
+            return false;
+        }
+        let k = Clifford::grade(x);
+        let upper = 2 * k > self.dim() || 2 * k == self.dim() && 2 * self.bit_to_blade(x) >= self.size();
+        upper && self.flip_by_complement(self.pseudoscalar_bits() ^ x)
+    }
+
+    /// Sign relating `x` to its complement, chosen such that `x ^ complement(x)` is the pseudoscalar.
+    const fn flip_by_complement(self, x: usize) -> bool {
+        Clifford::flip_by_anticommutativity(x, self.pseudoscalar_bits() ^ x)
+    }
+
+    /// `flip_by_complement` for the blades as laid out.
+    const fn flip_by_dual(self, x: usize) -> bool {
+        self.flip_by_complement(x) ^ self.flip_by_layout(x) ^ self.flip_by_layout(self.pseudoscalar_bits() ^ x)
+    }
+
+    const fn flip_by_reverse(x: usize) -> bool {
+        let k = Clifford::grade(x);
+        ((k * k.saturating_sub(1) / 2) & 1) != 0
+    }
+
+    const fn flip_by_involute(x: usize) -> bool {
+        (Clifford::grade(x) & 1) != 0
+    }
+
+    const fn flip_by_conjugate(x: usize) -> bool {
+        Clifford::flip_by_reverse(x) != Clifford::flip_by_involute(x)
+    }
+
+    /// `x` with its `dim` bits in reverse order, which turns lexicographic order into reversed bit order within
+    /// each grade.
+    const fn reverse_bits(self, x: usize) -> usize {
+        let mut y = 0usize;
+        let mut i = 0usize;
+        while i < self.dim() {
+            if x & (1 << i) != 0 {
+                y |= 1 << (self.dim() - 1 - i);
+            }
+            i += 1;
+        }
+        y
+    }
+
+    /// The first and last blade of grade `k` in bit order.
+    const fn grade_range(self, k: usize) -> (usize, usize) {
+        let bits = (1 << k) - 1;
+        (self.bit_to_blade(bits), self.bit_to_blade(bits << (self.dim() - k)))
+    }
+
+    pub const fn bit_to_blade(self, x: usize) -> usize {
+        if self.lexicographic {
+            let bit_order = Clifford { lexicographic: false, ..self };
+            let (first, last) = bit_order.grade_range(Clifford::grade(x));
+            return first + last - bit_order.bit_to_blade(self.reverse_bits(x));
+        }
+
         let mut n = 0usize;
         let mut i = 0usize;
         while i < self.size() {
@@ -114,7 +405,13 @@ impl Clifford {
         n
     }
 
-    const fn blade_to_bit(self, y: usize) -> usize {
+    pub const fn blade_to_bit(self, y: usize) -> usize {
+        if self.lexicographic {
+            let bit_order = Clifford { lexicographic: false, ..self };
+            let (first, last) = bit_order.grade_range(Clifford::grade(bit_order.blade_to_bit(y)));
+            return self.reverse_bits(bit_order.blade_to_bit(first + last - y));
+        }
+
         const fn blade_to_bit_helper(dim: usize, y: usize) -> (usize, usize) {
             let mut i = 0usize;
             let mut c = 1usize;
@@ -143,6 +440,11 @@ impl Clifford {
         }
         k
     }
+
+    /// `bit_to_blade` for every bit representation, for use in the hot loops of the products.
+    fn blades(self) -> Vec<usize> {
+        (0..self.size()).map(|x| self.bit_to_blade(x)).collect()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -153,17 +455,26 @@ pub struct Multivector<T, const C: Clifford> where
 }
 
 impl<T, const C: Clifford> Zero for Multivector<T, C> where
-T: Copy + Zero,
+T: Zero,
 [(); C.size()]: Sized,
 {
     fn zero() -> Self {
         Self {
-            data: [T::zero(); C.size()],
+            data: core::array::from_fn(|_| T::zero()),
         }
 
     }
 }
 
+impl<T, const C: Clifford> One for Multivector<T, C> where
+T: One + Zero,
+[(); C.size()]: Sized,
+{
+    fn one() -> Self {
+        Self::from_scalar(T::one())
+    }
+}
+
 impl<T, const C: Clifford> Float for Multivector<T, C> where
 T: Float,
 [(); C.size()]: Sized,
@@ -194,64 +505,463 @@ impl<T, const C: Clifford> Into<[T; C.size()]> for Multivector<T, C> where
     }
 }
 
+impl<T, const C: Clifford> Index<usize> for Multivector<T, C> where
+[(); C.size()]: Sized,
+{
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+impl<T, const C: Clifford> IndexMut<usize> for Multivector<T, C> where
+[(); C.size()]: Sized,
+{
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index]
+    }
+}
+
 impl<T, const C: Clifford> Multivector<T, C> where
 [(); C.size()]: Sized,
 {
-    pub fn inner_product<'a, 'b>(&'a self, other: &'b Self) -> T where
-    T: Clone + AddAssign + SubAssign + Zero,
-    &'a T: Mul<&'b T, Output = T>,
+    pub fn from_scalar(x: T) -> Self where
+    T: Zero,
+    {
+        let mut v = Self::zero();
+        v.data[0] = x;
+        v
+    }
+
+    /// The unit basis blade with bit representation `x`, e.g. `0b101` for `e1 ^ e3`.
+    pub fn basis(x: usize) -> Self where
+    T: One + Zero,
+    {
+        let mut v = Self::zero();
+        v.data[C.bit_to_blade(x)] = T::one();
+        v
+    }
+
+    /// The grade 1 multivector with the given coefficients for `e1, e2, ...`.
+    pub fn from_vector<I>(coefficients: I) -> Self where
+    T: Zero,
+    I: IntoIterator<Item = T>,
+    {
+        let mut v = Self::zero();
+        for (i, x) in coefficients.into_iter().enumerate().take(C.dim()) {
+            v.data[C.bit_to_blade(1 << i)] = x;
+        }
+        v
+    }
+
+    /// The coefficient of the basis blade with bit representation `x`.
+    pub fn coefficient(&self, x: usize) -> &T {
+        &self.data[C.bit_to_blade(x)]
+    }
+
+    pub fn coefficient_mut(&mut self, x: usize) -> &mut T {
+        &mut self.data[C.bit_to_blade(x)]
+    }
+
+    pub fn scalar_part(&self) -> T where
+    T: Clone,
+    {
+        self.data[0].clone()
+    }
+
+    /// The vector coefficients for `e1, e2, ...`.
+    pub fn vector_part(&self) -> Vec<T> where
+    T: Clone,
+    {
+        (0..C.dim()).map(|i| self.coefficient(1 << i).clone()).collect()
+    }
+
+    /// Project onto the blades of grade `k`.
+    pub fn grade(&self, k: usize) -> Self where
+    T: Clone + Zero,
+    {
+        self.map_blades(|x, v| if Clifford::grade(x) == k {
+            v.clone()
+        } else {
+            T::zero()
+        })
+    }
+
+    pub fn scale(&self, k: &T) -> Self where
+    T: Ring,
+    {
+        self.map_blades(|_, v| v.clone() * k.clone())
+    }
+
+    /// Apply `f` to each coefficient, e.g. to change the coefficient type.
+    pub fn map<U, F>(&self, f: F) -> Multivector<U, C> where
+    F: Fn(&T) -> U,
+    {
+        Multivector {
+            data: core::array::from_fn(|i| f(&self.data[i])),
+        }
+    }
+
+    fn map_blades<F>(&self, f: F) -> Self where
+    F: Fn(usize, &T) -> T,
+    {
+        Self {
+            data: core::array::from_fn(|i| f(C.blade_to_bit(i), &self.data[i])),
+        }
+    }
+
+    fn flip_blades<F>(&self, flip: F) -> Self where
+    T: Clone + Neg<Output = T>,
+    F: Fn(usize) -> bool,
+    {
+        self.map_blades(|x, v| if flip(x) {
+            v.clone().neg()
+        } else {
+            v.clone()
+        })
+    }
+
+    /// Reverse the order of the vectors in each basis blade.
+    pub fn reverse(&self) -> Self where
+    T: Clone + Neg<Output = T>,
+    {
+        self.flip_blades(Clifford::flip_by_reverse)
+    }
+
+    /// Negate the odd grades.
+    pub fn involute(&self) -> Self where
+    T: Clone + Neg<Output = T>,
+    {
+        self.flip_blades(Clifford::flip_by_involute)
+    }
+
+    /// Clifford conjugation, the composition of reversion and grade involution.
+    pub fn conjugate(&self) -> Self where
+    T: Clone + Neg<Output = T>,
+    {
+        self.flip_blades(Clifford::flip_by_conjugate)
+    }
+
+    /// Map each basis blade to its complement, such that `x ^ x.dual()` is the pseudoscalar for unit blades.
+    ///
+    /// Unlike multiplication by the inverse pseudoscalar, this is well defined for degenerate metrics.
+    pub fn dual(&self) -> Self where
+    T: Clone + Neg<Output = T>,
+    {
+        Self {
+            data: core::array::from_fn(|i| {
+                let x = C.pseudoscalar_bits() ^ C.blade_to_bit(i);
+                let v = self.data[C.bit_to_blade(x)].clone();
+                if C.flip_by_dual(x) {
+                    v.neg()
+                } else {
+                    v
+                }
+            }),
+        }
+    }
+
+    /// The inverse of `dual`.
+    pub fn undual(&self) -> Self where
+    T: Clone + Neg<Output = T>,
+    {
+        Self {
+            data: core::array::from_fn(|i| {
+                let x = C.blade_to_bit(i);
+                let v = self.data[C.bit_to_blade(C.pseudoscalar_bits() ^ x)].clone();
+                if C.flip_by_dual(x) {
+                    v.neg()
+                } else {
+                    v
+                }
+            }),
+        }
+    }
+
+    /// Sum the products of all pairs of basis blades for which `keep` holds.
+    fn product<F>(&self, other: &Self, keep: F) -> Self where
+    T: Ring,
+    F: Fn(usize, usize) -> bool,
+    {
+        self.product_by(other, keep, |i, j| C.flip_by_product(i, j))
+    }
+
+    /// `product` with the Euclidean metric on the same basis, for algorithms such as blade factorization
+    /// which work in any metric and need a non-degenerate one.
+    pub(crate) fn euclidean_product<F>(&self, other: &Self, keep: F) -> Self where
+    T: Ring,
+    F: Fn(usize, usize) -> bool,
+    {
+        self.product_by(other, keep, |i, j| {
+            Some(Clifford::flip_by_anticommutativity(i, j) ^ C.flip_by_layout(i) ^ C.flip_by_layout(j) ^ C.flip_by_layout(i ^ j))
+        })
+    }
+
+    fn product_by<F, G>(&self, other: &Self, keep: F, sign: G) -> Self where
+    T: Ring,
+    F: Fn(usize, usize) -> bool,
+    G: Fn(usize, usize) -> Option<bool>,
+    {
+        let blades = C.blades();
+        let mut x = Self::zero();
+        for i in 0..C.size() {
+            for j in 0..C.size() {
+                if !keep(i, j) {
+                    continue;
+                }
+                if let Some(flip) = sign(i, j) {
+                    let val = self.data[blades[i]].clone() * other.data[blades[j]].clone();
+                    if flip {
+                        x.data[blades[i ^ j]] -= val;
+                    } else {
+                        x.data[blades[i ^ j]] += val;
+                    }
+                }
+            }
+        }
+        x
+    }
+
+    /// The scalar product, i.e. the scalar part of the geometric product.
+    pub fn inner_product(&self, other: &Self) -> T where
+    T: Clone + AddAssign + SubAssign + Zero + Mul<Output = T>,
     {
         let mut v = T::zero();
         for (i, (x, y)) in zip(&self.data, &other.data).enumerate() {
             let j = C.blade_to_bit(i);
             if C.zero_by_form(j) {
                 continue;
-            } else if C.flip_by_form(j) {
-                v -= x * y;
+            } else if C.flip_by_form(j) != Clifford::flip_by_reverse(j) {
+                v -= x.clone() * y.clone();
             } else {
-                v += x * y;
+                v += x.clone() * y.clone();
             }
         }
         v
     }
 
-    pub fn outer_product<'a, 'b> (&'a self, other: &'b Self) -> Self where
-    T: Clone + AddAssign + Neg<Output = T>,
-    &'a T:  Mul<&'b T, Output = T>,
-    Multivector<T, C>: Zero,
+    pub fn geometric_product(&self, other: &Self) -> Self where
+    T: Ring,
     {
-        let mut x = Self::zero();
-        for i in 0..C.size() {
-            for j in 0..C.size() {
-                let k = i ^ j;
-                if i != j && !C.zero_by_form(k) {
-                    let lhs = &self.data[C.bit_to_blade(i)];
-                    let rhs = &other.data[C.bit_to_blade(j)];
-                    let val = lhs * rhs;
-                    let flip = Clifford::flip_by_anticommutativity(i , j) != C.flip_by_form(i & j);
-                    x.data[C.bit_to_blade(k)] += if flip {
-                        val.neg()
-                    } else {
-                        val
-                    };
+        self.product(other, |_, _| true)
+    }
 
-                }
+    pub fn outer_product(&self, other: &Self) -> Self where
+    T: Ring,
+    {
+        self.product(other, |i, j| i & j == 0)
+    }
+
+    /// The regressive product, dual to the outer product.
+    pub fn regressive_product(&self, other: &Self) -> Self where
+    T: Ring,
+    {
+        self.dual().outer_product(&other.dual()).undual()
+    }
+
+    pub fn left_contraction(&self, other: &Self) -> Self where
+    T: Ring,
+    {
+        self.product(other, |i, j| i & !j == 0)
+    }
+
+    pub fn right_contraction(&self, other: &Self) -> Self where
+    T: Ring,
+    {
+        self.product(other, |i, j| j & !i == 0)
+    }
+
+    /// The symmetric inner product of ganja.js, keeping the grade `|r - s|` part of each pair of blades.
+    pub fn dot(&self, other: &Self) -> Self where
+    T: Ring,
+    {
+        self.product(other, |i, j| i & !j == 0 || j & !i == 0)
+    }
+
+    /// The sandwich product `self * other * self.reverse()`.
+    pub fn sandwich(&self, other: &Self) -> Self where
+    T: Ring,
+    {
+        self.geometric_product(other).geometric_product(&self.reverse())
+    }
+
+    /// The scalar `self * self.reverse()`, which is the squared norm up to sign in non-Euclidean metrics.
+    pub fn norm_squared(&self) -> T where
+    T: Ring,
+    {
+        self.inner_product(&self.reverse())
+    }
+
+    /// The inverse of a versor or blade, `self.reverse() / self.norm_squared()`.
+    pub fn inverse(&self) -> Self where
+    T: Ring + Div<Output = T>,
+    {
+        let n = self.norm_squared();
+        self.reverse().map_blades(|_, v| v.clone() / n.clone())
+    }
+
+    pub fn norm(&self) -> T where
+    T: Real,
+    {
+        self.norm_squared().abs().sqrt()
+    }
+
+    pub fn normalized(&self) -> Self where
+    T: Real,
+    {
+        let n = self.norm();
+        self.map_blades(|_, v| v.clone() / n.clone())
+    }
+
+    /// The exponential, in closed form when `self` squares to a scalar and by scaling and squaring otherwise.
+    pub fn exp(&self) -> Self where
+    T: Real,
+    {
+        let square = self.geometric_product(self);
+        if square.grade(0) == square {
+            let s = square.scalar_part();
+            return if s < T::zero() {
+                let theta = s.neg().sqrt();
+                Self::from_scalar(theta.cos()) + self.scale(&(theta.sin() / theta))
+            } else if s > T::zero() {
+                let theta = s.sqrt();
+                Self::from_scalar(theta.cosh()) + self.scale(&(theta.sinh() / theta))
+            } else {
+                Self::one() + self.clone()
+            };
+        }
+
+        let half = T::from_f64(0.5);
+        let mut bound = T::zero();
+        for v in &self.data {
+            if v.abs() > bound {
+                bound = v.abs();
             }
         }
-        x
+        let mut scale = T::one();
+        let mut squarings = 0;
+        while bound.clone() * scale.clone() > half {
+            scale = scale * half.clone();
+            squarings += 1;
+        }
+
+        let x = self.scale(&scale);
+        let mut term = Self::one();
+        let mut sum = Self::one();
+        for n in 1..=16 {
+            term = term.geometric_product(&x).scale(&(T::one() / T::from_f64(n as f64)));
+            sum += term.clone();
+        }
+        for _ in 0..squarings {
+            sum = sum.geometric_product(&sum);
+        }
+        sum
     }
 
+    /// The logarithm of `a + b` where the non-scalar part `b` squares to a scalar, as for rotors, `None` otherwise
+    /// and when there is none, as for `a <= 0` with `b * b > 0`.
+    pub fn log(&self) -> Option<Self> where
+    T: Real,
+    {
+        let a = self.scalar_part();
+        let b = self.clone() - a.clone();
+        let square = b.geometric_product(&b);
+        if square.grade(0) != square {
+            return None;
+        }
+
+        let s = square.scalar_part();
+        let half = T::from_f64(0.5);
+        let n = a.clone() * a.clone() - s.clone();
+        if s < T::zero() {
+            let r = s.neg().sqrt();
+            let theta = r.atan2(&a);
+            Some(Self::from_scalar(n.ln() * half) + b.scale(&(theta / r)))
+        } else if s > T::zero() {
+            let r = s.sqrt();
+            // `exp(x + y b)` is `e^x (cosh(y) + sinh(y) b)`, whose scalar part is positive.
+            if n <= T::zero() || a <= T::zero() {
+                return None;
+            }
+            let theta = ((a.clone() + r.clone()) / (a - r.clone())).ln() * half.clone();
+            Some(Self::from_scalar(n.ln() * half) + b.scale(&(theta / r)))
+        } else if a > T::zero() {
+            Some(Self::from_scalar(a.ln()) + b.scale(&(T::one() / a)))
+        } else {
+            None
+        }
+    }
+
+    /// Spherical linear interpolation between unit rotors, from `self` at `t = 0` to `other` at `t = 1`
+    /// the shorter way round, `None` if the rotation between them is not simple, as for double rotations in 4D.
+    pub fn slerp(&self, other: &Self, t: T) -> Option<Self> where
+    T: Real,
+    {
+        let mut step = self.reverse().geometric_product(other);
+        if step.scalar_part() < T::zero() {
+            step = -step;
+        }
+        Some(self.geometric_product(&step.log()?.scale(&t).exp()))
+    }
+}
+
+impl<T, const C: Clifford> Mul<&Multivector<T, C>> for &Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Ring,
+{
+    type Output = Multivector<T, C>;
+    fn mul(self, other: &Multivector<T, C>) -> Self::Output {
+        self.geometric_product(other)
+    }
+}
+
+impl<T, const C: Clifford> Mul for Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Ring,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        self.geometric_product(&other)
+    }
+}
+
+impl<T, const C: Clifford> BitXor<&Multivector<T, C>> for &Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Ring,
+{
+    type Output = Multivector<T, C>;
+    fn bitxor(self, other: &Multivector<T, C>) -> Self::Output {
+        self.outer_product(other)
+    }
+}
+
+impl<T, const C: Clifford> BitAnd<&Multivector<T, C>> for &Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Ring,
+{
+    type Output = Multivector<T, C>;
+    fn bitand(self, other: &Multivector<T, C>) -> Self::Output {
+        self.regressive_product(other)
+    }
+}
+
+impl<T, const C: Clifford> BitOr<&Multivector<T, C>> for &Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Ring,
+{
+    type Output = Multivector<T, C>;
+    fn bitor(self, other: &Multivector<T, C>) -> Self::Output {
+        self.dot(other)
+    }
 }
 
-impl<'a, 'b, T, const C: Clifford> Mul<&'b Multivector<T, C>> for &'a Multivector<T, C> where
+impl<T, const C: Clifford> Not for &Multivector<T, C> where
 [(); C.size()]: Sized,
-T: Copy + AddAssign + Neg<Output = T> + SubAssign + Zero,
-&'a T: Mul<&'b T, Output = T>,
-Multivector<T, C>: Add<T, Output = Multivector<T, C>>,
+T: Clone + Neg<Output = T>,
 {
     type Output = Multivector<T, C>;
-    fn mul(self, other: &'b Multivector<T, C>) -> Self::Output {
-        self.outer_product(&other) + self.inner_product(&other)
+    fn not(self) -> Self::Output {
+        self.dual()
     }
 }
 
@@ -279,32 +989,83 @@ T: Clone + AddAssign,
     }
 }
 
-pub const STA: Clifford = Clifford {
-    positive: 1,
-    negative: 3,
-    zero: 0,
-};
+impl<T, const C: Clifford> Sub for Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Clone + SubAssign,
+{
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self::Output {
+        for i in 0..C.size() {
+            self.data[i] -= other.data[i].clone();
+        }
+        self
+    }
+}
 
-pub const fn vga(d: usize) -> Clifford {
-    Clifford {
-        positive: d,
-        negative: 0,
-        zero: 0,
+impl<T, const C: Clifford> Sub<T> for Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Clone + SubAssign,
+{
+    type Output = Self;
+    fn sub(mut self, other: T) -> Self::Output {
+        self.data[0] -= other;
+        self
+    }
+}
+
+impl<T, const C: Clifford> Neg for Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Clone + Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.map_blades(|_, v| v.clone().neg())
     }
 }
 
+impl<T, const C: Clifford> AddAssign for Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Clone + AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        for (x, y) in zip(&mut self.data, other.data) {
+            *x += y;
+        }
+    }
+}
+
+impl<T, const C: Clifford> SubAssign for Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Clone + SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        for (x, y) in zip(&mut self.data, other.data) {
+            *x -= y;
+        }
+    }
+}
+
+pub const QUATERNION: Clifford = Clifford::new(0, 2, 0);
+
+pub const fn vga(d: usize) -> Clifford {
+    Clifford::new(d, 0, 0)
+}
+
 pub const fn cga(d: usize) -> Clifford {
-    Clifford {
-        positive: d,
-        negative: 1,
-        zero: 0,
+    Clifford::new(d, 1, 0)
+}
+
+/// Minkowski spacetime, with the time vector squaring to `1` and the space vectors to `-1`, or the other way
+/// round if `mostly_plus`, and the time vector first or last.
+pub const fn spacetime(mostly_plus: bool, time_first: bool) -> Clifford {
+    let (t, s) = if mostly_plus { (-1, 1) } else { (1, -1) };
+    if time_first {
+        Clifford::from_metric(&[t, s, s, s])
+    } else {
+        Clifford::from_metric(&[s, s, s, t])
     }
 }
 
 pub const fn pga(d: usize) -> Clifford {
-    Clifford {
-        positive: d,
-        negative: 0,
-        zero: 1,
-    }
+    Clifford::new(d, 0, 1)
 }
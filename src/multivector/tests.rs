@@ -1,9 +1,12 @@
 use crate::multivector::*;
+use crate::tests::same;
 use core::ops::Rem;
+use num_rational::{BigRational, Ratio};
 use quickcheck::{Arbitrary, Gen, QuickCheck};
 
-const PGA3: Clifford = pga(3);
-const CGA2: Clifford = cga(1);
+// The layout of ganja.js: 1, e0, e1, e2, e3, e01, e02, e03, e12, e31, e23, e021, e013, e032, e123, e0123.
+const PGA3: Clifford = Clifford::from_metric(&[0, 1, 1, 1]).lexicographic().complementary();
+const QUAT: Clifford = QUATERNION;
 
 #[test]
 fn injective_bit_to_blade() {
@@ -64,20 +67,20 @@ impl From<ganja::PGA3D> for AMultivector<f64, PGA3> {
     }
 }
 
-impl Into<ganja::QUAT> for AMultivector<f64, CGA2> {
+impl Into<ganja::QUAT> for AMultivector<f64, QUAT> {
     fn into(self: Self) -> ganja::QUAT {
         let mut x = ganja::QUAT::zero();
-        for i in 0..CGA2.size() {
+        for i in 0..QUAT.size() {
             x[i] = self.0.data[i];
         }
         return x;
     }
 }
 
-impl From<ganja::QUAT> for AMultivector<f64, CGA2> {
+impl From<ganja::QUAT> for AMultivector<f64, QUAT> {
     fn from(v: ganja::QUAT) -> Self {
-        let mut x = Multivector::<f64, CGA2>::zero();
-        for i in 0..CGA2.size() {
+        let mut x = Multivector::<f64, QUAT>::zero();
+        for i in 0..QUAT.size() {
             x.data[i] = v[i];
         }
 
@@ -114,6 +117,15 @@ T: Arbitrary + Clone + Float + One + Rem<T, Output = T> + Send + Zero + 'static,
     }
 }
 
+impl<const C: Clifford> Arbitrary for AMultivector<i64, C> where
+[(); C.size()]: Sized,
+{
+    fn arbitrary(gen: &mut Gen) -> Self {
+        let data = core::array::from_fn(|_| i64::arbitrary(gen) % 16);
+        AMultivector(Multivector::from(data))
+    }
+}
+
 impl<T, const C: Clifford> Float for AMultivector<T, C> where
 T: Float,
 [(); C.size()]: Sized,
@@ -141,15 +153,15 @@ fn prop_pga3d_implementation() {
 
 #[test]
 fn prop_quat_implementation() {
-    fn reference_implementation((u, v): (AMultivector<f64, CGA2>, AMultivector<f64, CGA2>)) -> bool {
+    fn reference_implementation((u, v): (AMultivector<f64, QUAT>, AMultivector<f64, QUAT>)) -> bool {
         let ours = AMultivector(&u.0 * &v.0);
         let u_theirs: ganja::QUAT = u.into();
         let v_theirs: ganja::QUAT = v.into();
         let theirs = u_theirs * v_theirs;
-        let theirs = AMultivector::<f64, CGA2>::from(theirs);
+        let theirs = AMultivector::<f64, QUAT>::from(theirs);
         ours.is_nan() && theirs.is_nan() || ours == theirs
     }
-    QuickCheck::new().quickcheck(reference_implementation as fn((AMultivector<f64, CGA2>, AMultivector<f64, CGA2>)) -> bool);
+    QuickCheck::new().quickcheck(reference_implementation as fn((AMultivector<f64, QUAT>, AMultivector<f64, QUAT>)) -> bool);
 }
 
 #[test]
@@ -159,7 +171,7 @@ fn simple_quat() {
     let ours = AMultivector(&u.0 * &v.0);
     let theirs: ganja::QUAT = Into::<ganja::QUAT>::into(u) * Into::<ganja::QUAT>::into(v);
 
-    let theirs = AMultivector::<f64, CGA2>::from(theirs);
+    let theirs = AMultivector::<f64, QUAT>::from(theirs);
     assert_eq!(ours, theirs)
 }
 
@@ -167,5 +179,143 @@ fn simple_quat() {
 fn simple_form() {
     let u = 2;
     let v = 3;
-    assert!(CGA2.flip_by_form(u & v))
+    assert!(QUAT.flip_by_form(u & v))
+}
+
+#[test]
+fn exact_quaternion_cayley_table() {
+    let basis = [0b00, 0b01, 0b10, 0b11];
+    let table = [
+        [(1, 0b00), (1, 0b01), (1, 0b10), (1, 0b11)],
+        [(1, 0b01), (-1, 0b00), (1, 0b11), (-1, 0b10)],
+        [(1, 0b10), (-1, 0b11), (-1, 0b00), (1, 0b01)],
+        [(1, 0b11), (1, 0b10), (-1, 0b01), (-1, 0b00)],
+    ];
+    for (i, row) in table.iter().enumerate() {
+        for (j, (sign, k)) in row.iter().enumerate() {
+            let lhs = Multivector::<i64, QUAT>::basis(basis[i]);
+            let rhs = Multivector::<i64, QUAT>::basis(basis[j]);
+            assert_eq!(&lhs * &rhs, Multivector::basis(*k).scale(sign));
+        }
+    }
+}
+
+#[test]
+fn exact_pga_cayley_table() {
+    type M = Multivector<i64, { pga(3) }>;
+    let (e1, e2, e0) = (M::basis(0b0001), M::basis(0b0010), M::basis(0b1000));
+    assert_eq!(&e0 * &e0, M::zero());
+    assert_eq!(&e1 * &e1, M::one());
+    assert_eq!(&e1 * &e2, M::basis(0b0011));
+    assert_eq!(&e2 * &e1, -M::basis(0b0011));
+    assert_eq!(&e1 * &e0, M::basis(0b1001));
+    assert_eq!(&e0 * &e1, -M::basis(0b1001));
+    assert_eq!(&M::basis(0b1001) * &M::basis(0b1001), M::zero());
+    assert_eq!(&M::basis(0b0011) * &M::basis(0b0011), -M::one());
+}
+
+#[test]
+fn ganja_layout_pga() {
+    const BITS: [usize; 16] = [
+        0b0000, 0b0001, 0b0010, 0b0100, 0b1000, 0b0011, 0b0101, 0b1001,
+        0b0110, 0b1010, 0b1100, 0b0111, 0b1011, 0b1101, 0b1110, 0b1111,
+    ];
+    for (i, x) in BITS.iter().enumerate() {
+        assert_eq!(PGA3.bit_to_blade(*x), i);
+        assert_eq!(PGA3.blade_to_bit(i), *x);
+    }
+    type M = Multivector<i64, PGA3>;
+    assert_eq!(M::basis(0b0001) * M::basis(0b0001), M::zero());
+    assert_eq!(M::basis(0b1000) * M::basis(0b0010), M::basis(0b1010));
+    assert_eq!(M::basis(0b0101) * M::basis(0b1010), M::basis(0b1111));
+    assert_eq!(M::basis(0b0010).dual(), M::basis(0b1101));
+}
+
+#[test]
+fn bijective_lexicographic() {
+    const C: Clifford = cga(7).lexicographic();
+    for i in 0..C.size() {
+        assert_eq!(i, C.blade_to_bit(C.bit_to_blade(i)));
+    }
+}
+
+#[test]
+fn exact_involutions() {
+    type M = Multivector<i64, { vga(3) }>;
+    let x = M::from([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(x.reverse(), M::from([1, 2, 3, 4, -5, -6, -7, -8]));
+    assert_eq!(x.involute(), M::from([1, -2, -3, -4, 5, 6, 7, -8]));
+    assert_eq!(x.conjugate(), M::from([1, -2, -3, -4, -5, -6, -7, 8]));
+}
+
+#[test]
+fn exact_regressive_product() {
+    type M = Multivector<i64, { vga(3) }>;
+    assert_eq!(M::one().dual(), M::basis(0b111));
+    assert_eq!(&M::basis(0b011) & &M::basis(0b110), M::basis(0b010));
+    assert_eq!(&M::basis(0b001) ^ &M::basis(0b001), M::zero());
+}
+
+#[test]
+fn exact_rational_inverse() {
+    type M = Multivector<Ratio<i64>, { cga(2) }>;
+    let v = M::from_vector([Ratio::new(1, 2), Ratio::new(-2, 3), Ratio::new(1, 5)]);
+    assert_eq!(&v * &v.inverse(), M::one());
+
+    type B = Multivector<BigRational, { pga(2) }>;
+    let one = BigRational::from_integer(1.into());
+    let big = one.clone() / BigRational::from_integer(3u64.pow(30).into());
+    let r = B::one() + B::basis(0b011).scale(&big);
+    assert_eq!(&r * &r.inverse(), B::one());
+
+    let cos = one - big.clone() * big.clone();
+    let sin = big.clone() + big.clone();
+    assert_eq!(r.sandwich(&B::basis(0b001)), B::basis(0b001).scale(&cos) - B::basis(0b010).scale(&sin));
+}
+
+#[test]
+fn prop_exact_associativity() {
+    fn associative((u, v, w): (AMultivector<i64, PGA3>, AMultivector<i64, PGA3>, AMultivector<i64, PGA3>)) -> bool {
+        &(&u.0 * &v.0) * &w.0 == &u.0 * &(&v.0 * &w.0)
+            && &(&u.0 ^ &v.0) ^ &w.0 == &u.0 ^ &(&v.0 ^ &w.0)
+            && &(&u.0 & &v.0) & &w.0 == &u.0 & &(&v.0 & &w.0)
+    }
+    QuickCheck::new().quickcheck(associative as fn((AMultivector<i64, PGA3>, AMultivector<i64, PGA3>, AMultivector<i64, PGA3>)) -> bool);
+}
+
+#[test]
+fn prop_exact_reverse_and_dual() {
+    fn anti_automorphism((u, v): (AMultivector<i64, { cga(2) }>, AMultivector<i64, { cga(2) }>)) -> bool {
+        (&u.0 * &v.0).reverse() == &v.0.reverse() * &u.0.reverse()
+            && u.0.dual().undual() == u.0
+            && &Multivector::basis(cga(2).pseudoscalar_bits()) & &u.0 == u.0
+    }
+    QuickCheck::new().quickcheck(anti_automorphism as fn((AMultivector<i64, { cga(2) }>, AMultivector<i64, { cga(2) }>)) -> bool);
+}
+
+#[test]
+fn exp_log_boosts() {
+    type V = Multivector<f64, { vga(1) }>;
+    type M = Multivector<f64, { cga(1) }>;
+    for (a, b) in [(2.0, 1.0), (2.0, -1.5), (0.5, 0.1), (3.0, 0.0)] {
+        let x = V::from([a, b]);
+        assert!(same(&x.log().unwrap().exp(), &x));
+    }
+    assert!(V::from([-2.0, 1.0]).log().is_none());
+    assert!(V::from([1.0, 2.0]).log().is_none());
+
+    let boost = M::from_scalar(1.25) + M::basis(0b11).scale(&0.75);
+    assert!(same(&boost.log().unwrap().exp(), &boost));
+    assert!((-boost).log().is_none());
+}
+
+#[test]
+fn slerp_rotors() {
+    type V = Multivector<f64, { vga(3) }>;
+    let rotor = |angle: f64| V::from_scalar((angle / 2.0).cos()) - V::basis(0b011).scale(&(angle / 2.0).sin());
+    let (a, b) = (rotor(0.3), rotor(1.5));
+    assert!(same(&a.slerp(&b, 0.0).unwrap(), &a));
+    assert!(same(&a.slerp(&b, 1.0).unwrap(), &b));
+    assert!(same(&a.slerp(&b, 0.25).unwrap(), &rotor(0.6)));
+    assert!(same(&a.slerp(&-b.clone(), 0.25).unwrap(), &rotor(0.6)));
 }
@@ -0,0 +1,111 @@
+use crate::multivector::{Clifford, Multivector, One, Ring, Zero};
+use core::ops::Div;
+
+#[cfg(test)]
+mod tests;
+
+
+/// The extension of a linear map on vectors to the whole algebra, `f(a ^ b) == f(a) ^ f(b)` and `f(1) == 1`.
+///
+/// The matrix is row-major and acts on column vectors, with rows and columns following the basis vectors
+/// in the order of `vector_part`, so that column `i` is the image of `e_i`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outermorphism<T, const C: Clifford> {
+    matrix: Vec<Vec<T>>,
+}
+
+impl<T, const C: Clifford> Outermorphism<T, C> where
+T: Ring,
+[(); C.size()]: Sized,
+{
+    /// The outermorphism of a `C.dim()` by `C.dim()` matrix, `None` for a matrix of any other shape.
+    pub fn new(matrix: Vec<Vec<T>>) -> Option<Self> {
+        if matrix.len() != C.dim() || matrix.iter().any(|row| row.len() != C.dim()) {
+            return None;
+        }
+        Some(Outermorphism { matrix })
+    }
+
+    pub fn identity() -> Self {
+        Outermorphism {
+            matrix: (0..C.dim()).map(|i| (0..C.dim()).map(|j| if i == j { T::one() } else { T::zero() }).collect()).collect(),
+        }
+    }
+
+    pub fn matrix(&self) -> &[Vec<T>] {
+        &self.matrix
+    }
+
+    /// The image of a vector given by its coefficients, `f(e_i) = matrix[j][i] e_j`.
+    fn column(&self, i: usize) -> Multivector<T, C> {
+        Multivector::from_vector(self.matrix.iter().map(|row| row[i].clone()))
+    }
+
+    /// The image of each blade `e_i ^ e_j ^ ...` as the outer product of the images of its vectors.
+    pub fn apply(&self, x: &Multivector<T, C>) -> Multivector<T, C> {
+        let columns: Vec<Multivector<T, C>> = (0..C.dim()).map(|i| self.column(i)).collect();
+        (0..C.size()).fold(Multivector::zero(), |y, bits| {
+            // The product of the basis vectors is the basis blade up to the orientation of the layout.
+            let (blade, image) = (0..C.dim())
+                .filter(|i| bits & 1 << i != 0)
+                .fold((Multivector::<T, C>::one(), Multivector::one()), |(e, b), i| {
+                    (e.outer_product(&Multivector::basis(1 << i)), b.outer_product(&columns[i]))
+                });
+            y + image.scale(&(x.coefficient(bits).clone() * blade.coefficient(bits).clone()))
+        })
+    }
+
+    /// The factor `f(I) == det(f) I` by which the map scales the pseudoscalar.
+    pub fn determinant(&self) -> T {
+        let i = C.pseudoscalar_bits();
+        self.apply(&Multivector::basis(i)).coefficient(i).clone()
+    }
+
+    /// The map `self` after `other`.
+    pub fn compose(&self, other: &Self) -> Self {
+        let n = C.dim();
+        Outermorphism {
+            matrix: (0..n).map(|i| (0..n).map(|j| {
+                (0..n).fold(T::zero(), |s, k| s + self.matrix[i][k].clone() * other.matrix[k][j].clone())
+            }).collect()).collect(),
+        }
+    }
+
+    /// The adjoint with respect to the scalar product, `a . f(b) == f.adjoint()(a) . b` for vectors and,
+    /// extended to outermorphisms, for blades of equal grade.
+    ///
+    /// This is the transpose for Euclidean metrics and is only defined for non-degenerate ones.
+    pub fn adjoint(&self) -> Self {
+        let sign = |i: usize| C.negative_bits() & 1 << i != 0;
+        let n = C.dim();
+        Outermorphism {
+            matrix: (0..n).map(|i| (0..n).map(|j| {
+                let x = self.matrix[j][i].clone();
+                if sign(i) != sign(j) { -x } else { x }
+            }).collect()).collect(),
+        }
+    }
+
+    /// The inverse map, `f^-1(x) == f.adjoint()(x I) I^-1 / det(f)` on every grade, `None` when the
+    /// determinant vanishes.
+    ///
+    /// Only defined for non-degenerate metrics, like `adjoint`.
+    pub fn inverse(&self) -> Option<Self> where
+    T: Div<Output = T> + PartialEq,
+    {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+        let i = Multivector::<T, C>::basis(C.pseudoscalar_bits());
+        let (adjoint, i_inverse) = (self.adjoint(), i.inverse());
+        let n = C.dim();
+        let columns: Vec<Vec<T>> = (0..n).map(|k| {
+            let x = Multivector::basis(1 << k).geometric_product(&i);
+            adjoint.apply(&x).geometric_product(&i_inverse).vector_part()
+        }).collect();
+        Some(Outermorphism {
+            matrix: (0..n).map(|r| (0..n).map(|k| columns[k][r].clone() / det.clone()).collect()).collect(),
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{Multivector, Outermorphism};
+use crate::multivector::{cga, vga, Clifford};
+use num_rational::Ratio;
+
+type R = Ratio<i64>;
+
+fn matrix(m: [[i64; 3]; 3]) -> Vec<Vec<R>> {
+    m.iter().map(|row| row.iter().map(|&x| R::from_integer(x)).collect()).collect()
+}
+
+#[test]
+fn exact_outermorphism() {
+    type M = Multivector<i64, { vga(3) }>;
+    let f = Outermorphism::<i64, { vga(3) }>::new(vec![vec![2, 1, 0], vec![0, 1, 3], vec![1, 0, 1]]).unwrap();
+    let (a, b) = (M::from_vector([1, 2, 3]), M::from_vector([-1, 0, 2]));
+    assert_eq!(f.apply(&a), M::from_vector([4, 11, 4]));
+    assert_eq!(f.apply(&a.outer_product(&b)), f.apply(&a).outer_product(&f.apply(&b)));
+    assert_eq!(f.apply(&M::from_scalar(5)), M::from_scalar(5));
+    assert_eq!(f.determinant(), 5);
+
+    let g = Outermorphism::new(vec![vec![1, 0, 2], vec![1, 1, 0], vec![0, 3, 1]]).unwrap();
+    assert_eq!(f.compose(&g).determinant(), f.determinant() * g.determinant());
+    assert_eq!(f.compose(&g).apply(&a.outer_product(&b)), f.apply(&g.apply(&a.outer_product(&b))));
+    assert_eq!(f.compose(&Outermorphism::identity()), f);
+    assert!(Outermorphism::<i64, { vga(3) }>::new(vec![vec![1, 0], vec![0, 1]]).is_none());
+    assert!(Outermorphism::<i64, { vga(3) }>::new(vec![vec![1, 0, 0], vec![0, 1], vec![0, 0, 1]]).is_none());
+}
+
+#[test]
+fn exact_adjoint_and_inverse() {
+    type M = Multivector<R, { cga(2) }>;
+    let f = Outermorphism::<R, { cga(2) }>::new(matrix([[2, 1, 0], [0, 1, 3], [1, 0, 1]])).unwrap();
+    let vector = |x: [i64; 3]| M::from_vector(x.map(R::from_integer));
+    let (a, b, c) = (vector([1, 2, 3]), vector([-1, 0, 2]), vector([0, 1, 1]));
+    assert_eq!(a.inner_product(&f.apply(&b)), f.adjoint().apply(&a).inner_product(&b));
+    let (ab, bc) = (a.outer_product(&b), b.outer_product(&c));
+    assert_eq!(ab.inner_product(&f.apply(&bc)), f.adjoint().apply(&ab).inner_product(&bc));
+
+    let inverse = f.inverse().unwrap();
+    assert_eq!(inverse.compose(&f), Outermorphism::identity());
+    assert_eq!(inverse.apply(&f.apply(&bc)), bc);
+    assert!(Outermorphism::<R, { vga(3) }>::new(matrix([[1, 2, 3], [2, 4, 6], [0, 0, 1]])).unwrap().inverse().is_none());
+}
+
+#[test]
+fn exact_inverse_in_spacetime() {
+    const C: Clifford = Clifford::new(1, 3, 0);
+    let m = [[2, 1, 0, 0], [1, 1, 0, 0], [0, 3, 1, 0], [1, 0, 0, 1]];
+    let f = Outermorphism::<R, C>::new(m.iter().map(|row| row.iter().map(|&x| R::from_integer(x)).collect()).collect()).unwrap();
+    assert_eq!(f.determinant(), R::from_integer(1));
+    assert_eq!(f.compose(&f.inverse().unwrap()), Outermorphism::identity());
+}
@@ -0,0 +1,120 @@
+use crate::Pga;
+use crate::multivector::{One, Real};
+
+#[cfg(test)]
+mod tests;
+
+
+const E0: usize = 0b100;
+const E10: usize = 0b101;
+const E20: usize = 0b110;
+const E12: usize = 0b011;
+
+/// Plane geometry in 2D PGA, where lines `ax + by + c = 0` are the vectors `a e1 + b e2 + c e0` and
+/// points are their duals, so that `line ^ point` is `(ax + by + c)` times the pseudoscalar.
+impl<T> Pga<T, 2> where
+T: Real,
+{
+    pub fn line(a: T, b: T, c: T) -> Self {
+        Self::from_vector([a, b, c])
+    }
+
+    pub fn point(x: T, y: T) -> Self {
+        Self::from_vector([x, y, T::one()]).dual()
+    }
+
+    /// The ideal point in direction `(x, y)`, where parallel lines meet.
+    pub fn direction(x: T, y: T) -> Self {
+        Self::from_vector([x, y, T::zero()]).dual()
+    }
+
+    /// The point where two lines meet.
+    pub fn meet(&self, other: &Self) -> Self {
+        self.outer_product(other)
+    }
+
+    /// The line through two points.
+    pub fn join(&self, other: &Self) -> Self {
+        self.regressive_product(other)
+    }
+
+    /// The `(x, y)` coordinates of a point, `None` for ideal points.
+    pub fn to_point(&self) -> Option<(T, T)> {
+        let v = self.undual();
+        let w = v.coefficient(E0).clone();
+        if w == T::zero() {
+            return None;
+        }
+        Some((v.coefficient(0b001).clone() / w.clone(), v.coefficient(0b010).clone() / w))
+    }
+
+    /// The `(a, b, c)` coefficients of a line.
+    pub fn to_line(&self) -> (T, T, T) {
+        (self.coefficient(0b001).clone(), self.coefficient(0b010).clone(), self.coefficient(E0).clone())
+    }
+
+    /// Scale a point to unit weight, keeping its orientation for lines.
+    fn unitized(&self) -> Self {
+        let w = self.coefficient(E12).clone();
+        if w == T::zero() {
+            self.normalized()
+        } else {
+            self.scale(&(T::one() / w))
+        }
+    }
+
+    /// The distance between two points.
+    pub fn distance(&self, other: &Self) -> T {
+        self.unitized().join(&other.unitized()).norm()
+    }
+
+    /// The distance from a line to a point, positive on the side the normal `(a, b)` points to.
+    pub fn signed_distance(&self, point: &Self) -> T {
+        self.normalized().outer_product(&point.unitized()).coefficient(0b111).clone()
+    }
+
+    /// The signed angle from one line to another, counterclockwise.
+    pub fn angle(&self, other: &Self) -> T {
+        let (u, v) = (self.normalized(), other.normalized());
+        u.outer_product(&v).coefficient(E12).atan2(&u.inner_product(&v))
+    }
+
+    /// Project a point onto a line, or a line onto a point, giving the parallel line through it.
+    pub fn project(&self, onto: &Self) -> Self {
+        let onto = onto.unitized();
+        self.dot(&onto).geometric_product(&onto).unitized()
+    }
+
+    /// The direction from the projection of a point onto a line to the point itself.
+    pub fn reject(&self, from: &Self) -> Self {
+        self.unitized() - self.project(from)
+    }
+
+    /// Reflect a point or line in a line.
+    pub fn reflect(&self, line: &Self) -> Self {
+        line.geometric_product(&self.involute()).geometric_product(&line.inverse())
+    }
+
+    /// The rotor turning counterclockwise by `angle` about a point.
+    pub fn rotor(point: &Self, angle: T) -> Self {
+        let half = angle * T::from_f64(0.5);
+        Self::from_scalar(half.cos()) - point.unitized().scale(&half.sin())
+    }
+
+    /// The translator moving by `(x, y)`.
+    pub fn translator(x: T, y: T) -> Self {
+        let half = T::from_f64(0.5);
+        let mut t = Self::one();
+        *t.coefficient_mut(E10) = x * half.clone();
+        *t.coefficient_mut(E20) = y * half;
+        t
+    }
+
+    pub fn rotate(&self, point: &Self, angle: T) -> Self {
+        Self::rotor(point, angle).sandwich(self)
+    }
+
+    pub fn translate(&self, x: T, y: T) -> Self {
+        Self::translator(x, y).sandwich(self)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Pga;
+use crate::tests::exact;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+type P = Pga<f64, 2>;
+
+fn at(p: &P, x: f64, y: f64) -> bool {
+    let (u, v) = p.to_point().unwrap();
+    exact(u, x) && exact(v, y)
+}
+
+#[test]
+fn constructors_round_trip() {
+    assert!(at(&P::point(2.0, -3.0), 2.0, -3.0));
+    assert_eq!(P::line(1.0, 2.0, 3.0).to_line(), (1.0, 2.0, 3.0));
+    assert_eq!(P::direction(1.0, 0.0).to_point(), None);
+}
+
+#[test]
+fn meet_and_join() {
+    assert!(at(&P::line(1.0, 0.0, -2.0).meet(&P::line(0.0, 1.0, -3.0)), 2.0, 3.0));
+    let (a, b, c) = P::point(0.0, 1.0).join(&P::point(1.0, 2.0)).to_line();
+    assert!(exact(a * 1.0 + b * 2.0 + c, 0.0) && exact(a * 0.0 + b * 1.0 + c, 0.0));
+    let parallel = P::line(1.0, 1.0, 0.0).meet(&P::line(1.0, 1.0, 5.0));
+    assert_eq!(parallel.to_point(), None);
+}
+
+#[test]
+fn distances_and_angles() {
+    assert!(exact(P::point(1.0, 1.0).distance(&P::point(4.0, 5.0)), 5.0));
+    let horizontal = P::line(0.0, 2.0, -2.0);
+    assert!(exact(horizontal.signed_distance(&P::point(7.0, 4.0)), 3.0));
+    assert!(exact(horizontal.signed_distance(&P::point(7.0, -1.0)), -2.0));
+    assert!(exact(P::line(1.0, 0.0, 0.0).angle(&P::line(1.0, 1.0, 0.0)), FRAC_PI_4));
+    assert!(exact(P::line(1.0, 1.0, 0.0).angle(&P::line(1.0, 0.0, 0.0)), -FRAC_PI_4));
+}
+
+#[test]
+fn projection_and_rejection() {
+    let horizontal = P::line(0.0, 1.0, -1.0);
+    let point = P::point(2.0, 3.0);
+    assert!(at(&point.project(&horizontal), 2.0, 1.0));
+    let rejection = point.reject(&horizontal);
+    assert!(rejection.to_point().is_none());
+    assert!(at(&(P::point(2.0, 1.0) + rejection), 2.0, 3.0));
+
+    let through = horizontal.project(&point);
+    assert!(exact(through.signed_distance(&point), 0.0));
+    assert!(exact(through.angle(&horizontal).sin(), 0.0));
+}
+
+#[test]
+fn motions() {
+    let diagonal = P::line(1.0, -1.0, 0.0);
+    assert!(at(&P::point(2.0, 0.0).reflect(&diagonal), 0.0, 2.0));
+    let (a, b, c) = P::line(1.0, 0.0, -1.0).reflect(&diagonal).to_line();
+    assert!(exact(a, 0.0) && exact(c / b, -1.0));
+
+    assert!(at(&P::point(2.0, 1.0).rotate(&P::point(1.0, 1.0), FRAC_PI_2), 1.0, 2.0));
+    assert!(at(&P::point(2.0, 1.0).translate(-1.0, 4.0), 1.0, 5.0));
+    let moved = P::line(1.0, 0.0, -1.0).translate(2.0, 0.0);
+    assert!(exact(moved.signed_distance(&P::point(3.0, 0.0)), 0.0));
+}
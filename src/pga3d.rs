@@ -0,0 +1,229 @@
+use crate::Pga;
+use crate::multivector::{One, Real, Zero};
+
+#[cfg(test)]
+mod tests;
+
+
+const E0: usize = 0b1000;
+const E12: usize = 0b0011;
+const E13: usize = 0b0101;
+const E23: usize = 0b0110;
+const E10: usize = 0b1001;
+const E20: usize = 0b1010;
+const E30: usize = 0b1100;
+const I: usize = 0b1111;
+
+pub(crate) fn dot<T: Real>(u: &[T; 3], v: &[T; 3]) -> T {
+    u[0].clone() * v[0].clone() + u[1].clone() * v[1].clone() + u[2].clone() * v[2].clone()
+}
+
+pub(crate) fn cross<T: Real>(u: &[T; 3], v: &[T; 3]) -> [T; 3] {
+    [
+        u[1].clone() * v[2].clone() - u[2].clone() * v[1].clone(),
+        u[2].clone() * v[0].clone() - u[0].clone() * v[2].clone(),
+        u[0].clone() * v[1].clone() - u[1].clone() * v[0].clone(),
+    ]
+}
+
+fn length<T: Real>(u: &[T; 3]) -> T {
+    dot(u, u).sqrt()
+}
+
+/// Whether the sine of an angle is small enough to call two directions parallel.
+fn parallel<T: Real>(sin: &T) -> bool {
+    sin.abs() <= T::epsilon().sqrt()
+}
+
+/// Space geometry in 3D PGA, where planes `ax + by + cz + d = 0` are the vectors `a e1 + b e2 + c e3 + d e0`,
+/// points are their duals and lines are bivectors, after ganja.js' `PGA3D`.
+impl<T> Pga<T, 3> where
+T: Real,
+{
+    pub fn plane(a: T, b: T, c: T, d: T) -> Self {
+        Self::from_vector([a, b, c, d])
+    }
+
+    pub fn point(x: T, y: T, z: T) -> Self {
+        Self::from_vector([x, y, z, T::one()]).dual()
+    }
+
+    /// The ideal point in direction `(x, y, z)`, where parallel lines meet.
+    pub fn direction(x: T, y: T, z: T) -> Self {
+        Self::from_vector([x, y, z, T::zero()]).dual()
+    }
+
+    /// The line with direction `d` and moment `m` about the origin, see `plucker`.
+    pub fn from_plucker(d: [T; 3], m: [T; 3]) -> Self {
+        let [dx, dy, dz] = d;
+        let [mx, my, mz] = m;
+        let mut line = Self::zero();
+        *line.coefficient_mut(E23) = dx.neg();
+        *line.coefficient_mut(E13) = dy;
+        *line.coefficient_mut(E12) = dz.neg();
+        *line.coefficient_mut(E10) = mx;
+        *line.coefficient_mut(E20) = my;
+        *line.coefficient_mut(E30) = mz;
+        line
+    }
+
+    /// The line through two points, directed from `self` to `other`.
+    pub fn line_through(&self, other: &Self) -> Self {
+        self.join(other)
+    }
+
+    /// The plane through three points.
+    pub fn plane_through(&self, b: &Self, c: &Self) -> Self {
+        self.join(b).join(c)
+    }
+
+    /// The intersection of two flats, e.g. the line where two planes meet or the point where a line meets a plane.
+    pub fn meet(&self, other: &Self) -> Self {
+        self.outer_product(other)
+    }
+
+    /// The flat spanned by two flats, e.g. the line through two points or the plane through a line and a point.
+    pub fn join(&self, other: &Self) -> Self {
+        self.regressive_product(other)
+    }
+
+    /// The `(x, y, z)` coordinates of a point, `None` for ideal points.
+    pub fn to_point(&self) -> Option<(T, T, T)> {
+        let v = self.undual();
+        let w = v.coefficient(E0).clone();
+        if w == T::zero() {
+            return None;
+        }
+        let [x, y, z] = [0b0001, 0b0010, 0b0100].map(|e| v.coefficient(e).clone() / w.clone());
+        Some((x, y, z))
+    }
+
+    /// The `(a, b, c, d)` coefficients of a plane.
+    pub fn to_plane(&self) -> (T, T, T, T) {
+        let [a, b, c, d] = [0b0001, 0b0010, 0b0100, E0].map(|e| self.coefficient(e).clone());
+        (a, b, c, d)
+    }
+
+    /// The Plücker coordinates `(d, m)` of a line, its direction `d` and moment `m = p x d` for any point `p` on it.
+    ///
+    /// The line through points `p` and `q` has direction `q - p`.
+    pub fn plucker(&self) -> ([T; 3], [T; 3]) {
+        let d = [
+            self.coefficient(E23).clone().neg(),
+            self.coefficient(E13).clone(),
+            self.coefficient(E12).clone().neg(),
+        ];
+        let m = [E10, E20, E30].map(|e| self.coefficient(e).clone());
+        (d, m)
+    }
+
+    fn normal(&self) -> [T; 3] {
+        [0b0001, 0b0010, 0b0100].map(|e| self.coefficient(e).clone())
+    }
+
+    /// The highest grade present, 1 for planes, 2 for lines and 3 for points, where a grade whose coefficients
+    /// are all within rounding of zero, relative to the largest one, is absent.
+    fn flat(&self) -> usize {
+        let largest = |x: &Self| (0..16).fold(T::zero(), |m, i| if x[i].abs() > m { x[i].abs() } else { m });
+        let tolerance = T::epsilon().sqrt() * largest(self);
+        (1..4).rev().find(|k| largest(&self.grade(*k)) > tolerance).unwrap_or(0)
+    }
+
+    /// Scale to unit weight, i.e. a point with `w = 1` or a plane or line with a unit normal or direction.
+    fn unitized(&self) -> Self {
+        if self.flat() == 3 {
+            let w = self.undual().coefficient(E0).clone();
+            if w != T::zero() {
+                return self.scale(&(T::one() / w));
+            }
+        }
+        self.normalized()
+    }
+
+    /// The point of a line or plane nearest to the origin.
+    fn anchor(&self) -> Self {
+        Self::point(T::zero(), T::zero(), T::zero()).project(self)
+    }
+
+    /// The distance between two flats, signed for a point and a plane, positive on the side the normal points to.
+    ///
+    /// Intersecting flats are at distance zero.
+    pub fn distance(&self, other: &Self) -> T {
+        let (a, b) = (self.unitized(), other.unitized());
+        match (a.flat(), b.flat()) {
+            (3, 3) | (2, 3) | (3, 2) => a.join(&b).norm(),
+            (1, 3) => a.meet(&b).coefficient(I).clone(),
+            (3, 1) => b.meet(&a).coefficient(I).clone(),
+            (2, 2) => {
+                let sin = length(&cross(&a.plucker().0, &b.plucker().0));
+                if parallel(&sin) {
+                    a.anchor().distance(&b)
+                } else {
+                    a.meet(&b).coefficient(I).abs() / sin
+                }
+            }
+            (1, 2) | (2, 1) | (1, 1) => {
+                let sin = length(&cross(&a.orientation(), &b.orientation()));
+                let cos = dot(&a.orientation(), &b.orientation()).abs();
+                if (a.flat() == b.flat() && parallel(&sin)) || (a.flat() != b.flat() && parallel(&cos)) {
+                    if a.flat() == 1 {
+                        a.meet(&b.anchor()).coefficient(I).abs()
+                    } else {
+                        b.meet(&a.anchor()).coefficient(I).abs()
+                    }
+                } else {
+                    T::zero()
+                }
+            }
+            _ => T::zero(),
+        }
+    }
+
+    /// The normal of a plane or the direction of a line.
+    fn orientation(&self) -> [T; 3] {
+        if self.flat() == 1 {
+            self.normal()
+        } else {
+            self.plucker().0
+        }
+    }
+
+    /// The angle between two lines, two planes or a line and a plane, in `[0, pi]` for lines and planes
+    /// and `[0, pi / 2]` between a line and a plane; zero when either is a point.
+    pub fn angle(&self, other: &Self) -> T {
+        let (u, v) = (self.orientation(), other.orientation());
+        match (self.flat(), other.flat()) {
+            (1, 1) | (2, 2) => length(&cross(&u, &v)).atan2(&dot(&u, &v)),
+            (1, 2) | (2, 1) => dot(&u, &v).abs().atan2(&length(&cross(&u, &v))),
+            _ => T::zero(),
+        }
+    }
+
+    /// Orthogonally project onto another flat, e.g. a point onto a line or plane, or a line onto a plane.
+    ///
+    /// Projecting a larger flat onto a point gives the parallel flat through that point.
+    pub fn project(&self, onto: &Self) -> Self {
+        self.dot(onto).geometric_product(&onto.inverse()).unitized()
+    }
+
+    /// The direction from the projection of a point onto a flat to the point itself.
+    pub fn reject(&self, from: &Self) -> Self {
+        self.unitized() - self.project(from)
+    }
+
+    /// The rotor turning by `angle` about a line, counterclockwise when looking against its direction.
+    pub fn rotor(line: &Self, angle: T) -> Self {
+        let half = angle * T::from_f64(0.5);
+        Self::from_scalar(half.cos()) + line.normalized().scale(&half.sin())
+    }
+
+    /// The translator moving by `(x, y, z)`.
+    pub fn translator(x: T, y: T, z: T) -> Self {
+        let half = T::from_f64(0.5);
+        let mut t = Self::one();
+        *t.coefficient_mut(E10) = x * half.clone();
+        *t.coefficient_mut(E20) = y * half.clone();
+        *t.coefficient_mut(E30) = z * half;
+        t
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Pga;
+use crate::tests::exact;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+type P = Pga<f64, 3>;
+
+fn at(p: &P, x: f64, y: f64, z: f64) -> bool {
+    let (u, v, w) = p.to_point().unwrap();
+    exact(u, x) && exact(v, y) && exact(w, z)
+}
+
+fn on(plane: &P, x: f64, y: f64, z: f64) -> bool {
+    let (a, b, c, d) = plane.to_plane();
+    exact(a * x + b * y + c * z + d, 0.0)
+}
+
+#[test]
+fn constructors_round_trip() {
+    assert!(at(&P::point(1.0, -2.0, 3.0), 1.0, -2.0, 3.0));
+    assert_eq!(P::plane(1.0, 2.0, 3.0, 4.0).to_plane(), (1.0, 2.0, 3.0, 4.0));
+    assert_eq!(P::direction(0.0, 0.0, 1.0).to_point(), None);
+    let line = P::from_plucker([1.0, 2.0, 3.0], [3.0, 0.0, -1.0]);
+    assert_eq!(line.plucker(), ([1.0, 2.0, 3.0], [3.0, 0.0, -1.0]));
+}
+
+#[test]
+fn lines_and_planes() {
+    let line = P::point(0.0, 1.0, 0.0).line_through(&P::point(1.0, 1.0, 0.0));
+    assert_eq!(line.plucker(), ([1.0, 0.0, 0.0], [0.0, 0.0, -1.0]));
+
+    let axis = P::plane(1.0, 0.0, 0.0, 0.0).meet(&P::plane(0.0, 1.0, 0.0, 0.0));
+    let (d, m) = axis.plucker();
+    assert!(exact(d[0], 0.0) && exact(d[1], 0.0) && d[2] != 0.0);
+    assert_eq!(m, [0.0, 0.0, 0.0]);
+
+    let plane = P::point(1.0, 0.0, 0.0).plane_through(&P::point(0.0, 1.0, 0.0), &P::point(0.0, 0.0, 1.0));
+    assert!(on(&plane, 1.0, 0.0, 0.0) && on(&plane, 0.0, 1.0, 0.0) && on(&plane, 0.0, 0.0, 1.0));
+
+    assert!(at(&line.meet(&P::plane(1.0, 0.0, 0.0, -4.0)), 4.0, 1.0, 0.0));
+    assert!(on(&line.join(&P::point(0.0, 0.0, 5.0)), 7.0, 1.0, 0.0));
+}
+
+#[test]
+fn distances() {
+    let origin = P::point(0.0, 0.0, 0.0);
+    let z_axis = origin.line_through(&P::point(0.0, 0.0, 1.0));
+    let ground = P::plane(0.0, 0.0, 2.0, 0.0);
+    assert!(exact(origin.distance(&P::point(1.0, 2.0, 2.0)), 3.0));
+    assert!(exact(ground.distance(&P::point(1.0, 1.0, 3.0)), 3.0));
+    assert!(exact(P::point(1.0, 1.0, -3.0).distance(&ground), -3.0));
+    assert!(exact(z_axis.distance(&P::point(3.0, 4.0, 7.0)), 5.0));
+    let rounded = z_axis.clone() + P::point(1.0, 1.0, 1.0).scale(&1e-17);
+    assert!(exact(rounded.distance(&P::point(3.0, 4.0, 7.0)), 5.0));
+
+    let skew = P::point(2.0, 0.0, 0.0).line_through(&P::point(2.0, 1.0, 5.0));
+    assert!(exact(z_axis.distance(&skew), 2.0));
+    let parallel = P::point(0.0, 3.0, 0.0).line_through(&P::point(0.0, 3.0, -1.0));
+    assert!(exact(z_axis.distance(&parallel), 3.0));
+    assert!(exact(z_axis.distance(&origin.line_through(&P::point(1.0, 0.0, 0.0))), 0.0));
+
+    let x_line = P::point(0.0, 0.0, 4.0).line_through(&P::point(1.0, 0.0, 4.0));
+    assert!(exact(ground.distance(&x_line), 4.0));
+    assert!(exact(z_axis.distance(&ground), 0.0));
+    assert!(exact(ground.distance(&P::plane(0.0, 0.0, -1.0, 6.0)), 6.0));
+    assert!(exact(ground.distance(&P::plane(1.0, 0.0, 1.0, 6.0)), 0.0));
+}
+
+#[test]
+fn angles() {
+    let origin = P::point(0.0, 0.0, 0.0);
+    let x_axis = origin.line_through(&P::point(1.0, 0.0, 0.0));
+    let diagonal = P::point(0.0, 0.0, 3.0).line_through(&P::point(1.0, 1.0, 3.0));
+    assert!(exact(x_axis.angle(&diagonal), FRAC_PI_4));
+    assert!(exact(P::plane(1.0, 0.0, 0.0, 0.0).angle(&P::plane(0.0, 0.0, 1.0, 9.0)), FRAC_PI_2));
+    assert!(exact(x_axis.angle(&P::plane(1.0, 0.0, 1.0, 0.0)), FRAC_PI_4));
+    assert!(exact(diagonal.angle(&P::plane(0.0, 0.0, 1.0, 0.0)), 0.0));
+}
+
+#[test]
+fn projection_and_rejection() {
+    let line = P::point(0.0, 1.0, 0.0).line_through(&P::point(1.0, 1.0, 0.0));
+    let point = P::point(5.0, 3.0, 4.0);
+    assert!(at(&point.project(&line), 5.0, 1.0, 0.0));
+    assert!(at(&point.project(&P::plane(0.0, 0.0, 1.0, -1.0)), 5.0, 3.0, 1.0));
+    let rejection = point.reject(&line);
+    assert!(rejection.to_point().is_none());
+    assert!(at(&(P::point(5.0, 1.0, 0.0) + rejection), 5.0, 3.0, 4.0));
+
+    let shadow = line.project(&P::plane(0.0, 1.0, 0.0, 0.0));
+    assert!(exact(shadow.distance(&P::point(7.0, 0.0, 0.0)), 0.0));
+    assert!(exact(shadow.angle(&line), 0.0));
+    let through = line.project(&point);
+    assert!(exact(through.distance(&point), 0.0));
+}
+
+#[test]
+fn rotors_and_translators() {
+    let z_axis = P::point(0.0, 0.0, 0.0).line_through(&P::point(0.0, 0.0, 1.0));
+    assert!(at(&P::rotor(&z_axis, FRAC_PI_2).sandwich(&P::point(1.0, 0.0, 2.0)), 0.0, 1.0, 2.0));
+    assert!(at(&P::translator(1.0, -2.0, 3.0).sandwich(&P::point(1.0, 1.0, 1.0)), 2.0, -1.0, 4.0));
+}
@@ -0,0 +1,188 @@
+use crate::{Motor, Pga, Vga};
+use crate::decomposition::solve;
+use crate::form::eigen;
+use crate::motor::quaternion_to_rotor;
+use crate::multivector::{Real, Zero};
+use crate::pga3d::{cross, dot};
+use core::cmp::Ordering;
+
+#[cfg(test)]
+mod tests;
+
+
+impl<T> Vga<T, 3> where
+T: Real,
+{
+    /// The rotor `R` minimizing `sum_i w_i |b_i - R a_i R~|^2` for weighted pairs of points `a_i` of `source`
+    /// and `b_i` of `target`, rotating about the origin; `None` unless the slices have the same length and the
+    /// weights a positive sum.
+    ///
+    /// This maximizes `sum_i w_i <b_i R a_i R~>`, a quadratic form in the coefficients of `R` on
+    /// `1, e12, e13, e23` with the matrix `<F(E_j) E_k~>` of the linear function `F(X) = sum_i w_i b_i X a_i`,
+    /// whose largest eigenvector is the rotor. Too few points, as for collinear ones, leave it undetermined,
+    /// with the two largest eigenvalues equal, and then this is `None` too.
+    pub fn align(source: &[[T; 3]], target: &[[T; 3]], weights: &[T]) -> Option<Self> {
+        total_weight(source, target, weights)?;
+        let basis = [0, 0b011, 0b101, 0b110].map(Self::basis);
+        let pairs: Vec<(Self, Self, &T)> = source.iter().zip(target).zip(weights)
+            .map(|((a, b), w)| (Self::from_vector(a.clone()), Self::from_vector(b.clone()), w))
+            .collect();
+        let images: Vec<Self> = basis.iter().map(|e| pairs.iter().fold(Self::zero(), |f, (a, b, w)| {
+            f + b.geometric_product(e).geometric_product(a).scale(w)
+        })).collect();
+        let matrix: Vec<Vec<T>> = images.iter()
+            .map(|f| basis.iter().map(|e| f.geometric_product(&e.reverse()).scalar_part()).collect())
+            .collect();
+        let (values, vectors) = eigen(&matrix);
+        let mut order = [0, 1, 2, 3];
+        order.sort_by(|&i, &j| values[j].partial_cmp(&values[i]).unwrap_or(Ordering::Equal));
+        let (k, second) = (order[0], order[1]);
+        let scale = values.iter().fold(T::zero(), |m, x| if x.abs() > m { x.abs() } else { m });
+        if values[k].clone() - values[second].clone() <= T::epsilon().sqrt() * scale {
+            return None;
+        }
+        Some(basis.iter().zip(&vectors).fold(Self::zero(), |r, (e, row)| r + e.scale(&row[k])))
+    }
+}
+
+impl<T> Pga<T, 3> where
+T: Real,
+{
+    /// The motor of the rigid motion `M` minimizing `sum_i w_i |b_i - M(a_i)|^2` for weighted pairs of points
+    /// `a_i` of `source` and `b_i` of `target`, with the same conditions as `Vga::align`.
+    ///
+    /// The optimal motion takes the weighted centroid of `source` to that of `target`, so it is the rotor
+    /// aligning the points about their centroids followed by the translation between the centroids.
+    pub fn register(source: &[[T; 3]], target: &[[T; 3]], weights: &[T]) -> Option<Self> {
+        let total = total_weight(source, target, weights)?;
+        let centroid = |points: &[[T; 3]]| points.iter().zip(weights).fold([T::zero(), T::zero(), T::zero()], |c, (p, w)| {
+            core::array::from_fn(|i| c[i].clone() + p[i].clone() * w.clone() / total.clone())
+        });
+        let (a, b) = (centroid(source), centroid(target));
+        let centered = |points: &[[T; 3]], c: &[T; 3]| -> Vec<[T; 3]> {
+            points.iter().map(|p| core::array::from_fn(|i| p[i].clone() - c[i].clone())).collect()
+        };
+        let rotor = Vga::align(&centered(source, &a), &centered(target, &b), weights)?;
+        let r = rotor.sandwich(&Vga::from_vector(a)).vector_part();
+        let translation = core::array::from_fn(|i| b[i].clone() - r[i].clone());
+        Some(Self::from(Motor::from_rotation_translation(&rotor, translation)))
+    }
+
+    /// A motor fitted by `register` to the pairs of points that it moves to within `threshold` of their
+    /// targets, which are marked in the returned vector, robust to wrong pairs by RANSAC: each of `iterations`
+    /// trials fits three pairs drawn from a generator seeded by `seed`, and the motor with the most inliers
+    /// is refitted to all of them. `None` for fewer than three pairs, when no trial fits, or when the best
+    /// inliers are collinear, which leaves the rotation about their line undetermined.
+    pub fn register_ransac(source: &[[T; 3]], target: &[[T; 3]], threshold: T, iterations: usize, seed: u64) -> Option<(Self, Vec<bool>)> {
+        let n = source.len();
+        if n < 3 || target.len() != n {
+            return None;
+        }
+        let mut state = seed;
+        let mut draw = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize % n
+        };
+        let ones = [T::one(), T::one(), T::one()];
+        let inliers = |motor: &Self| -> Vec<bool> {
+            let motor = Motor::from_even(motor);
+            source.iter().zip(target).map(|(a, b)| {
+                let p = move_point(&motor, a);
+                let d: [T; 3] = core::array::from_fn(|i| p[i].clone() - b[i].clone());
+                dot(&d, &d) <= threshold.clone() * threshold.clone()
+            }).collect()
+        };
+        let mut best: Option<Vec<bool>> = None;
+        for _ in 0..iterations {
+            let i = draw();
+            let j = draw();
+            let k = draw();
+            if i == j || j == k || i == k {
+                continue;
+            }
+            let sample = |points: &[[T; 3]]| [i, j, k].map(|x| points[x].clone());
+            if let Some(motor) = Self::register(&sample(source), &sample(target), &ones) {
+                let mask = inliers(&motor);
+                let count = mask.iter().filter(|&&x| x).count();
+                if best.as_ref().is_none_or(|b| count > b.iter().filter(|&&x| x).count()) {
+                    best = Some(mask);
+                }
+            }
+        }
+        let best = best?;
+        let pick = |points: &[[T; 3]]| -> Vec<[T; 3]> {
+            points.iter().zip(&best).filter(|(_, &x)| x).map(|(p, _)| p.clone()).collect()
+        };
+        let (a, b) = (pick(source), pick(target));
+        let motor = Self::register(&a, &b, &vec![T::one(); a.len()])?;
+        let mask = inliers(&motor);
+        Some((motor, mask))
+    }
+
+    /// The motor `M` minimizing `sum_i w_i ((M(a_i) - b_i) . n_i)^2`, the weighted squared distances of the
+    /// points `a_i` of `source` to the planes through the points `b_i` of `target` with normals `n_i`, by
+    /// Gauss-Newton iterations starting from the motor `initial`.
+    ///
+    /// Each step solves for the small rotation `w` and translation `t` with `M(a_i) + w x M(a_i) + t` on the
+    /// planes and applies them after `M`. As it slides points along their planes, this converges faster than
+    /// fitting points to points when the pairs are only nearest neighbours, but needs a close initial motor.
+    /// `None` when the planes leave the motion undetermined, as for parallel ones.
+    pub fn register_point_to_plane(source: &[[T; 3]], target: &[[T; 3]], normals: &[[T; 3]], weights: &[T], initial: &Self, iterations: usize) -> Option<Self> {
+        total_weight(source, target, weights)?;
+        if normals.len() != source.len() {
+            return None;
+        }
+        let mut motor = Motor::from_even(initial).normalized();
+        for _ in 0..iterations {
+            let mut a = vec![vec![T::zero(); 6]; 6];
+            let mut g = vec![T::zero(); 6];
+            for (((p, q), n), w) in source.iter().zip(target).zip(normals).zip(weights) {
+                let p = move_point(&motor, p);
+                let d: [T; 3] = core::array::from_fn(|i| p[i].clone() - q[i].clone());
+                let r = dot(&d, n);
+                let j: Vec<T> = cross(&p, n).iter().chain(n).cloned().collect();
+                for (row, x) in a.iter_mut().zip(&j) {
+                    for (y, z) in row.iter_mut().zip(&j) {
+                        *y += w.clone() * x.clone() * z.clone();
+                    }
+                }
+                for (y, x) in g.iter_mut().zip(&j) {
+                    *y -= w.clone() * x.clone() * r.clone();
+                }
+            }
+            let x = solve(a, g)?;
+            let w = [x[0].clone(), x[1].clone(), x[2].clone()];
+            let t = [x[3].clone(), x[4].clone(), x[5].clone()];
+            let step = dot(&w, &w) + dot(&t, &t);
+            motor = (Motor::from_rotation_translation(&rotation(&w), t) * motor).normalized();
+            if step <= T::epsilon() * T::epsilon() {
+                break;
+            }
+        }
+        Some(Self::from(motor))
+    }
+}
+
+/// The sum of the weights, `None` unless the slices match and it is positive.
+fn total_weight<T: Real>(source: &[[T; 3]], target: &[[T; 3]], weights: &[T]) -> Option<T> {
+    if source.len() != target.len() || source.len() != weights.len() {
+        return None;
+    }
+    let total = weights.iter().fold(T::zero(), |s, w| s + w.clone());
+    if total > T::zero() { Some(total) } else { None }
+}
+
+fn move_point<T: Real>(motor: &Motor<T>, p: &[T; 3]) -> [T; 3] {
+    let [x, y, z] = p.clone();
+    let (x, y, z) = motor.transform_point(&Pga::<T, 3>::point(x, y, z)).to_point().unwrap();
+    [x, y, z]
+}
+
+/// The rotor turning by `|w|` about the axis `w`, so that `v` goes to `v + w x v` to first order.
+fn rotation<T: Real>(w: &[T; 3]) -> Vga<T, 3> {
+    let theta = dot(w, w).sqrt();
+    let half = theta.clone() * T::from_f64(0.5);
+    let s = if theta == T::zero() { T::from_f64(0.5) } else { half.sin() / theta };
+    let [x, y, z] = w.clone().map(|c| c * s.clone());
+    quaternion_to_rotor([half.cos(), x, y, z])
+}
@@ -0,0 +1,133 @@
+use crate::{Motor, Pga, Vga};
+use crate::pga3d::cross;
+use crate::tests::close;
+
+type P = Pga<f64, 3>;
+
+/// A scattered cloud of points, the same on every run.
+fn cloud(n: usize) -> Vec<[f64; 3]> {
+    (0..n).map(|i| {
+        let t = i as f64;
+        [3.0 * (1.3 * t).sin(), 2.0 * (0.7 * t + 0.4).cos(), 1.5 * (2.9 * t + 1.0).sin()]
+    }).collect()
+}
+
+fn motion() -> Motor<f64> {
+    Motor::from_translation(1.0, -2.0, 0.5) * Motor::from_axis_angle([1.0, -2.0, 0.5], 2.5)
+}
+
+fn move_all(motor: &Motor<f64>, points: &[[f64; 3]]) -> Vec<[f64; 3]> {
+    points.iter().map(|&[x, y, z]| {
+        let (x, y, z) = motor.transform_point(&P::point(x, y, z)).to_point().unwrap();
+        [x, y, z]
+    }).collect()
+}
+
+fn same_motion(lhs: &Motor<f64>, rhs: &Motor<f64>) -> bool {
+    let points = cloud(10);
+    move_all(lhs, &points).iter().zip(move_all(rhs, &points)).all(|(p, q)| (0..3).all(|i| close(p[i], q[i])))
+}
+
+#[test]
+fn align() {
+    let source = cloud(20);
+    let rotor = Vga::<f64, 3>::from_axis_angle([0.3, 1.0, -0.2], 2.0);
+    let target: Vec<[f64; 3]> = source.iter().map(|&p| {
+        let v = rotor.sandwich(&Vga::from_vector(p)).vector_part();
+        [v[0], v[1], v[2]]
+    }).collect();
+    let found = Vga::align(&source, &target, &[1.0; 20]).unwrap();
+    assert!(close(found.geometric_product(&found.reverse()).scalar_part(), 1.0));
+    assert!(same_motion(&Motor::from_rotation_translation(&found, [0.0; 3]), &Motor::from_rotation_translation(&rotor, [0.0; 3])));
+    assert!(Vga::align(&source, &target[1..], &[1.0; 19]).is_none());
+    assert!(Vga::align(&source, &target, &[0.0; 20]).is_none());
+
+    // Any rotation about the line through collinear points fits them equally well.
+    let line: Vec<[f64; 3]> = (0..5).map(|i| [i as f64 - 2.0, 0.5 * (i as f64 - 2.0), 0.0]).collect();
+    let turned: Vec<[f64; 3]> = line.iter().map(|&p| {
+        let v = rotor.sandwich(&Vga::from_vector(p)).vector_part();
+        [v[0], v[1], v[2]]
+    }).collect();
+    assert!(Vga::align(&line, &turned, &[1.0; 5]).is_none());
+    assert!(Vga::align(&line, &line, &[1.0; 5]).is_none());
+    assert!(P::register(&line, &turned, &[1.0; 5]).is_none());
+}
+
+#[test]
+fn register() {
+    let source = cloud(20);
+    let target = move_all(&motion(), &source);
+    let found = P::register(&source, &target, &[1.0; 20]).unwrap();
+    assert!(same_motion(&Motor::from_even(&found), &motion()));
+
+    // Pairs of zero weight do not count, and weights do not matter for exact pairs.
+    let mut wrong = target.clone();
+    wrong[3] = [10.0, 0.0, 0.0];
+    wrong[7] = [0.0, -4.0, 2.0];
+    let weights: Vec<f64> = (0..20).map(|i| if i == 3 || i == 7 { 0.0 } else { 1.0 + i as f64 }).collect();
+    let found = P::register(&source, &wrong, &weights).unwrap();
+    assert!(same_motion(&Motor::from_even(&found), &motion()));
+}
+
+#[test]
+fn register_noisy() {
+    let source = cloud(200);
+    let noise = cloud(201);
+    let target: Vec<[f64; 3]> = move_all(&motion(), &source).iter().zip(&noise[1..])
+        .map(|(p, n)| [p[0] + 1e-3 * n[1], p[1] + 1e-3 * n[2], p[2] + 1e-3 * n[0]])
+        .collect();
+    let found = Motor::from_even(&P::register(&source, &target, &[1.0; 200]).unwrap());
+    let expected = motion();
+    // Up to sign, as `M` and `-M` are the same motion.
+    let sign = if found.coefficients()[0] * expected.coefficients()[0] < 0.0 { -1.0 } else { 1.0 };
+    assert!(found.coefficients().iter().zip(expected.coefficients()).all(|(a, b)| (sign * a - b).abs() < 1e-3));
+}
+
+#[test]
+fn register_ransac() {
+    let source = cloud(40);
+    let mut target = move_all(&motion(), &source);
+    let outliers: Vec<usize> = (0..40).filter(|i| i % 3 == 1).collect();
+    for (&i, junk) in outliers.iter().zip(cloud(60).into_iter().skip(40)) {
+        target[i] = junk.map(|x| 4.0 * x);
+    }
+    let (found, inliers) = P::register_ransac(&source, &target, 1e-6, 50, 7).unwrap();
+    assert!(same_motion(&Motor::from_even(&found), &motion()));
+    assert!(inliers.iter().enumerate().all(|(i, &x)| x != outliers.contains(&i)));
+
+    // Plain least squares is thrown off by the outliers.
+    let plain = P::register(&source, &target, &[1.0; 40]).unwrap();
+    assert!(!same_motion(&Motor::from_even(&plain), &motion()));
+    assert!(P::register_ransac(&source[..2], &target[..2], 1e-6, 50, 7).is_none());
+
+    // Every fit through the one point off the line misses its target by more than the threshold, so the best
+    // inliers are the points on the line, which fix no rotation about it.
+    let mut line: Vec<[f64; 3]> = (0..10).map(|i| [0.5 * i as f64 - 2.0, 0.0, 0.0]).collect();
+    let mut moved = line.clone();
+    line.push([0.0, 1.0, 0.0]);
+    moved.push([0.0, 1.0, 0.3]);
+    assert!(P::register_ransac(&line, &moved, 0.02, 50, 7).is_none());
+    assert!(P::register_ransac(&line, &moved, 0.05, 50, 7).is_some());
+}
+
+#[test]
+fn register_point_to_plane() {
+    let source = cloud(30);
+    let expected = Motor::from_translation(0.2, -0.1, 0.3) * Motor::from_axis_angle([1.0, 2.0, -1.0], 0.3);
+    let target = move_all(&expected, &source);
+    let normals: Vec<[f64; 3]> = cloud(60).into_iter().skip(30).map(|n| {
+        let length = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+        n.map(|x| x / length)
+    }).collect();
+    // Targets slid along their planes mark the same planes.
+    let slid: Vec<[f64; 3]> = target.iter().zip(&normals).map(|(q, n)| {
+        let s = cross(n, &[0.6, 0.0, 0.8]);
+        [q[0] + s[0], q[1] + s[1], q[2] + s[2]]
+    }).collect();
+    let found = P::register_point_to_plane(&source, &slid, &normals, &[1.0; 30], &P::from(Motor::identity()), 20).unwrap();
+    assert!(same_motion(&Motor::from_even(&found), &expected));
+
+    // Planes with one normal cannot fix the motion along them.
+    let flat = vec![[0.0, 0.0, 1.0]; 30];
+    assert!(P::register_point_to_plane(&source, &slid, &flat, &[1.0; 30], &P::from(Motor::identity()), 20).is_none());
+}
@@ -0,0 +1,216 @@
+use crate::multivector::{Clifford, Multivector, Ring, Zero};
+use core::ops::Div;
+
+#[cfg(test)]
+mod tests;
+
+
+/// `Cl(0, n)`. The entries below are spelled through this alias rather than `Quaternion`, whose size the
+/// generic helpers could not otherwise bound.
+type Negative<T, const N: usize> = Multivector<T, { Clifford::new(0, N, 0) }>;
+
+/// The quaternions `a + b i + c j + d k` with `i = e1`, `j = e2` and `k = e12`, the entries of matrix
+/// representations. Real and complex representations keep to the real numbers and to `a + b i`.
+pub type Entry<T> = Negative<T, 2>;
+
+/// A square matrix of quaternions, row-major.
+pub type Matrix<T> = Vec<Vec<Entry<T>>>;
+
+/// The division algebra over which a Clifford algebra is a full matrix algebra.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field {
+    Real,
+    Complex,
+    Quaternion,
+}
+
+/// A non-degenerate algebra is isomorphic to the `size` by `size` matrices over `field`, or to pairs of
+/// them if `double`, represented as block diagonal matrices of twice the size. The pseudoscalar `I` is then
+/// central with `I * I == 1`, and the upper and lower blocks are the ideals of `(1 + I) / 2` and `(1 - I) / 2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Representation {
+    pub field: Field,
+    pub size: usize,
+    pub double: bool,
+}
+
+impl Clifford {
+    /// The matrix algebra of `Cl(p, q)` from the Bott periodicity table, which depends on `p - q` modulo 8;
+    /// `None` for degenerate algebras.
+    pub const fn representation(self) -> Option<Representation> {
+        if self.zero_bits() != 0 {
+            return None;
+        }
+        let d = self.dim();
+        let q = usize::count_ones(self.negative_bits()) as usize;
+        let (field, double, halved) = match (d - q + 7 * q) % 8 {
+            0 | 2 => (Field::Real, false, d),
+            1 => (Field::Real, true, d - 1),
+            3 | 7 => (Field::Complex, false, d - 1),
+            4 | 6 => (Field::Quaternion, false, d - 2),
+            _ => (Field::Quaternion, true, d - 3),
+        };
+        Some(Representation { field, size: 1 << (halved / 2), double })
+    }
+}
+
+impl<T, const C: Clifford> Multivector<T, C> where
+[(); C.size()]: Sized,
+{
+    /// The matrix of `self` in the faithful representation of `C.representation()`, such that the
+    /// geometric product becomes the matrix product.
+    ///
+    /// Basis vectors map to generators built up from small algebras by `Cl(p + 1, q + 1) = M2(Cl(p, q))` and
+    /// `Cl(p + 4, q) = Cl(p, q + 4)`, assigned in order of index to the positive and negative basis vectors.
+    /// Panics for degenerate algebras.
+    pub fn to_matrix(&self) -> Matrix<T> where
+    T: Ring,
+    {
+        let blades = blade_matrices::<T, C>();
+        let n = blades[0].len();
+        let mut m = zero(n);
+        for (bits, blade) in blades.iter().enumerate() {
+            let x = self.coefficient(bits);
+            for (row, b) in m.iter_mut().zip(blade) {
+                for (y, b) in row.iter_mut().zip(b) {
+                    *y += b.scale(x);
+                }
+            }
+        }
+        m
+    }
+
+    /// The element represented by a matrix of `to_matrix`, by the real part of the trace of its product
+    /// with the inverse of each basis blade.
+    pub fn from_matrix(m: &[Vec<Entry<T>>]) -> Self where
+    T: Ring + Div<Output = T>,
+    {
+        let blades = blade_matrices::<T, C>();
+        let n = m.len();
+        let size = (0..n).fold(T::zero(), |s, _| s + T::one());
+        let mut x = Self::zero();
+        for (bits, blade) in blades.iter().enumerate() {
+            // Basis blades square to `1` or `-1`, so their inverses are themselves up to sign.
+            let sign = Self::basis(bits).inner_product(&Self::basis(bits));
+            let trace = (0..n).fold(T::zero(), |t, i| (0..n).fold(t, |t, k| {
+                t + blade[i][k].geometric_product(&m[k][i]).scalar_part()
+            }));
+            *x.coefficient_mut(bits) = trace * sign / size.clone();
+        }
+        x
+    }
+}
+
+/// The matrix of each basis blade by bit representation.
+fn blade_matrices<T, const C: Clifford>() -> Vec<Matrix<T>> where
+T: Ring,
+{
+    let mut vectors = vector_matrices::<T, C>();
+    let n = vectors.first().map_or(1, |v| v.len());
+    if C.representation().is_some_and(|r| r.double) {
+        // The generators make the pseudoscalar diagonal with entries `1` and `-1`, and as it is central
+        // every element is block diagonal once the rows and columns where it is `1` come first.
+        let vectors_i64 = vector_matrices::<i64, C>();
+        let pseudoscalar = vectors_i64.iter().fold(identity(n), |w, v| product(&w, v));
+        let sign = |i: usize| *pseudoscalar[i][i].coefficient(0);
+        let order: Vec<usize> = (0..n).filter(|&i| sign(i) == 1).chain((0..n).filter(|&i| sign(i) == -1)).collect();
+        vectors = vectors.iter().map(|v| order.iter().map(|&i| order.iter().map(|&k| v[i][k].clone()).collect()).collect()).collect();
+    }
+    let mut blades = vec![identity(n)];
+    for bits in 1..C.size() {
+        // The blade without its last vector is already known.
+        let last = usize::BITS - 1 - bits.leading_zeros();
+        blades.push(product(&blades[bits ^ 1 << last], &vectors[last as usize]));
+    }
+    // Those are the products of the vectors in increasing order, which the layout may orient the other way.
+    blades.into_iter().enumerate().map(|(bits, b)| if C.flip_by_layout(bits) { negate(&b) } else { b }).collect()
+}
+
+/// The generators of `generators` assigned in order of index to the basis vectors.
+fn vector_matrices<T, const C: Clifford>() -> Vec<Matrix<T>> where
+T: Ring,
+{
+    assert!(C.zero_bits() == 0, "degenerate algebras have no faithful matrix representation");
+    let q = usize::count_ones(C.negative_bits()) as usize;
+    let (positive, negative) = generators::<T>(C.dim() - q, q);
+    let (mut positive, mut negative) = (positive.into_iter(), negative.into_iter());
+    (0..C.dim())
+        .map(|i| if C.negative_bits() & 1 << i != 0 { negative.next() } else { positive.next() }.unwrap())
+        .collect()
+}
+
+/// Anticommuting matrices squaring to `1` and `-1` generating a faithful representation of `Cl(p, q)`.
+fn generators<T>(p: usize, q: usize) -> (Vec<Matrix<T>>, Vec<Matrix<T>>) where
+T: Ring,
+{
+    let one = || Entry::<T>::from_scalar(T::one());
+    let unit = |bits: usize| Entry::<T>::basis(bits);
+    let o = Entry::<T>::zero;
+    if p >= 1 && q >= 1 {
+        // `Cl(p, q) = M2(Cl(p - 1, q - 1))`, with the old generators as `diag(x, -x)`.
+        let (positive, negative) = generators::<T>(p - 1, q - 1);
+        let n = positive.first().or(negative.first()).map_or(1, |v| v.len());
+        let lift = |x: &Matrix<T>| block(x, &zero(n), &zero(n), &negate(x));
+        let one = identity(n);
+        let mut positive: Vec<Matrix<T>> = positive.iter().map(lift).collect();
+        let mut negative: Vec<Matrix<T>> = negative.iter().map(lift).collect();
+        positive.push(block(&zero(n), &one, &one, &zero(n)));
+        negative.push(block(&zero(n), &negate(&one), &one, &zero(n)));
+        return (positive, negative);
+    }
+    if p > 4 || q > 3 {
+        // Four generators `f` of one sign times their product `w` give four of the other, as `w * w == 1`.
+        let (positive, negative) = if p > 4 { generators::<T>(p - 4, 4) } else { generators::<T>(4, q - 4) };
+        let (mut keep, four) = if p > 4 { (positive, negative) } else { (negative, positive) };
+        let w = four.iter().skip(1).fold(four[0].clone(), |w, f| product(&w, f));
+        keep.extend(four.iter().map(|f| product(f, &w)));
+        return if p > 4 { (keep, Vec::new()) } else { (Vec::new(), keep) };
+    }
+    match (p, q) {
+        (0, 0) => (Vec::new(), Vec::new()),
+        (1, 0) => (vec![vec![vec![one(), o()], vec![o(), -one()]]], Vec::new()),
+        (2, 0) | (3, 0) => {
+            let mut positive = vec![
+                vec![vec![o(), one()], vec![one(), o()]],
+                vec![vec![one(), o()], vec![o(), -one()]],
+            ];
+            if p == 3 {
+                positive.push(vec![vec![o(), -unit(0b01)], vec![unit(0b01), o()]]);
+            }
+            (positive, Vec::new())
+        }
+        // `[[0, u], [u~, 0]]` for the orthonormal quaternions `u = 1, i, j, k`.
+        (4, 0) => ([0b00, 0b01, 0b10, 0b11].iter().map(|&u| {
+            vec![vec![o(), unit(u)], vec![unit(u).conjugate(), o()]]
+        }).collect(), Vec::new()),
+        (0, 1) => (Vec::new(), vec![vec![vec![unit(0b01)]]]),
+        (0, 2) => (Vec::new(), vec![vec![vec![unit(0b01)]], vec![vec![unit(0b10)]]]),
+        _ => (Vec::new(), [0b01, 0b10, 0b11].iter().map(|&u| vec![vec![unit(u), o()], vec![o(), -unit(u)]]).collect()),
+    }
+}
+
+fn zero<T: Ring>(n: usize) -> Matrix<T> {
+    vec![vec![Entry::zero(); n]; n]
+}
+
+fn identity<T: Ring>(n: usize) -> Matrix<T> {
+    (0..n).map(|i| (0..n).map(|j| if i == j { Entry::from_scalar(T::one()) } else { Entry::zero() }).collect()).collect()
+}
+
+fn negate<T: Ring>(m: &Matrix<T>) -> Matrix<T> {
+    m.iter().map(|row| row.iter().map(|x| -x.clone()).collect()).collect()
+}
+
+fn product<T: Ring>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let n = a.len();
+    (0..n).map(|i| (0..n).map(|j| {
+        (0..n).fold(Entry::zero(), |s, k| s + a[i][k].geometric_product(&b[k][j]))
+    }).collect()).collect()
+}
+
+/// The matrix `[[a, b], [c, d]]` of blocks.
+fn block<T: Ring>(a: &Matrix<T>, b: &Matrix<T>, c: &Matrix<T>, d: &Matrix<T>) -> Matrix<T> {
+    let top = a.iter().zip(b).map(|(x, y)| x.iter().chain(y).cloned().collect());
+    let bottom = c.iter().zip(d).map(|(x, y)| x.iter().chain(y).cloned().collect());
+    top.chain(bottom).collect()
+}
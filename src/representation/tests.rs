@@ -0,0 +1,88 @@
+use super::{Entry, Field, Matrix, generators, product};
+use crate::Multivector;
+use crate::multivector::{Clifford, Zero, cga, vga};
+use quickcheck::QuickCheck;
+
+fn scaled(m: &Matrix<i64>, k: i64) -> Matrix<i64> {
+    m.iter().map(|row| row.iter().map(|x| x.scale(&k)).collect()).collect()
+}
+
+#[test]
+fn exact_bott_periodicity() {
+    for p in 0..=8 {
+        for q in 0..=8 - p {
+            let representation = Clifford::new(p, q, 0).representation().unwrap();
+            let (positive, negative) = generators::<i64>(p, q);
+            assert_eq!((positive.len(), negative.len()), (p, q));
+            let vectors: Vec<(&Matrix<i64>, i64)> = positive.iter().map(|v| (v, 1)).chain(negative.iter().map(|v| (v, -1))).collect();
+            let n = vectors.first().map_or(1, |(v, _)| v.len());
+            assert_eq!(n, representation.size << representation.double as usize);
+            let identity = super::identity::<i64>(n);
+            for (i, (u, s)) in vectors.iter().enumerate() {
+                assert_eq!(product(u, u), scaled(&identity, *s));
+                for (v, _) in &vectors[..i] {
+                    assert_eq!(product(u, v), scaled(&product(v, u), -1));
+                }
+                let used = match representation.field {
+                    Field::Real => 0b00,
+                    Field::Complex => 0b01,
+                    Field::Quaternion => 0b11,
+                };
+                assert!(u.iter().flatten().all(|x| (0..4).all(|bits| bits & !used == 0 || *x.coefficient(bits) == 0)));
+            }
+        }
+    }
+}
+
+fn homomorphism<const C: Clifford>((u, v): (Vec<i64>, Vec<i64>)) -> bool where
+[(); C.size()]: Sized,
+{
+    let element = |x: &[i64]| Multivector::<i64, C>::from(core::array::from_fn(|i| x.get(i).map_or(0, |x| x % 16)));
+    let (u, v) = (element(&u), element(&v));
+    let m = u.to_matrix();
+    (&u * &v).to_matrix() == product(&m, &v.to_matrix()) && Multivector::<i64, C>::from_matrix(&m) == u
+}
+
+#[test]
+fn prop_exact_matrix_representation() {
+    QuickCheck::new().quickcheck(homomorphism::<{ vga(3) }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+    QuickCheck::new().quickcheck(homomorphism::<{ cga(3) }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+    QuickCheck::new().quickcheck(homomorphism::<{ Clifford::new(0, 3, 0) }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+    QuickCheck::new().quickcheck(homomorphism::<{ Clifford::new(1, 3, 0) }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+    QuickCheck::new().quickcheck(homomorphism::<{ Clifford::new(0, 5, 0) }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+    QuickCheck::new().quickcheck(homomorphism::<{ Clifford::from_metric(&[-1, 1, -1, 1]) }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+    QuickCheck::new().quickcheck(homomorphism::<{ vga(5) }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+    QuickCheck::new().quickcheck(homomorphism::<{ vga(3).lexicographic().complementary() }> as fn((Vec<i64>, Vec<i64>)) -> bool);
+}
+
+
+fn block_diagonal<const C: Clifford>() where
+[(); C.size()]: Sized,
+{
+    let representation = C.representation().unwrap();
+    assert!(representation.double);
+    let x = Multivector::<i64, C>::from(core::array::from_fn(|i| (i as i64 * 7) % 5 - 2));
+    let m = x.to_matrix();
+    let half = representation.size;
+    assert_eq!(m.len(), 2 * half);
+    assert!((0..2 * half).all(|i| (0..2 * half).all(|k| (i < half) == (k < half) || m[i][k] == Entry::zero())));
+    assert_eq!(Multivector::<i64, C>::from_matrix(&m), x);
+
+    // The upper block is where the pseudoscalar is `1`.
+    let pseudoscalar = Multivector::<i64, C>::basis(C.pseudoscalar_bits()).to_matrix();
+    let one = super::identity::<i64>(half);
+    assert!((0..2 * half).all(|i| (0..2 * half).all(|k| {
+        let expected = if (i < half) != (k < half) { Entry::zero() } else if i < half { one[i][k].clone() } else { -one[i - half][k - half].clone() };
+        pseudoscalar[i][k] == expected
+    })));
+}
+
+#[test]
+fn exact_double_layout() {
+    block_diagonal::<{ vga(1) }>();
+    block_diagonal::<{ cga(2) }>();
+    block_diagonal::<{ vga(5) }>();
+    block_diagonal::<{ Clifford::new(1, 4, 0) }>();
+    block_diagonal::<{ Clifford::new(3, 2, 0) }>();
+    block_diagonal::<{ Clifford::from_metric(&[-1, 1, -1, 1, 1]) }>();
+}
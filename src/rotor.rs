@@ -0,0 +1,112 @@
+use crate::{Motor, Vga};
+use crate::multivector::{Clifford, Multivector, One, Real};
+use core::cmp::Ordering;
+
+#[cfg(test)]
+mod tests;
+
+
+impl<T, const C: Clifford> Multivector<T, C> where
+[(); C.size()]: Sized,
+T: Real,
+{
+    /// Scale `1 + b a` to a unit rotor, `None` when it vanishes because `b` is `-a`.
+    ///
+    /// For blades of grade two, `r * r.reverse()` also has a grade 4 part `q`, a multiple of the pseudoscalar
+    /// of the space spanned by both blades, which commutes with everything the rotor touches. The inverse
+    /// square root of `s + q` is then `u (1 + q / m) / 2 + v (1 - q / m) / 2`, where `m` is the magnitude of `q`
+    /// and `u`, `v` are the inverse square roots of `s + m` and `s - m`.
+    fn unit_rotor(r: Self) -> Option<Self> {
+        let n = r.geometric_product(&r.reverse());
+        let s = n.scalar_part();
+        let q = n.grade(4);
+        let radius = q.inner_product(&q).sqrt();
+        if s.clone() - radius.clone() <= T::epsilon() {
+            return None;
+        }
+        if radius == T::zero() {
+            return Some(r.scale(&(T::one() / s.sqrt())));
+        }
+        let half = T::from_f64(0.5);
+        let u = T::one() / (s.clone() + radius.clone()).sqrt();
+        let v = T::one() / (s - radius.clone()).sqrt();
+        let scale = Self::from_scalar((u.clone() + v.clone()) * half.clone()) + q.scale(&((u - v) * half / radius));
+        Some(r.geometric_product(&scale))
+    }
+
+    /// The unit vector along the basis vector with the largest component orthogonal to `self`.
+    fn orthogonal(&self) -> Self {
+        let inverse = self.inverse();
+        (0..C.dim())
+            .map(|i| {
+                let e = Self::basis(1 << i);
+                let projection = e.left_contraction(self).geometric_product(&inverse);
+                e - projection
+            })
+            .max_by(|x, y| x.norm().partial_cmp(&y.norm()).unwrap_or(Ordering::Equal))
+            .expect("no vector is orthogonal to the whole space")
+            .normalized()
+    }
+
+    /// The unit vector in the plane of a 2-blade along the basis vector with the largest component in it.
+    fn within(&self) -> Self {
+        (0..C.dim())
+            .map(|i| Self::basis(1 << i).left_contraction(self))
+            .max_by(|x, y| x.norm().partial_cmp(&y.norm()).unwrap_or(Ordering::Equal))
+            .unwrap()
+            .normalized()
+    }
+
+    /// The rotor turning the direction of vector `a` into that of `b` in their common plane, the smallest such
+    /// rotation, so that `between(a, b).sandwich(a)` points along `b`.
+    ///
+    /// Antiparallel vectors are turned half way round in a plane containing the basis vector furthest from
+    /// their line.
+    pub fn between(a: &Self, b: &Self) -> Self {
+        let (a, b) = (a.normalized(), b.normalized());
+        Self::unit_rotor(Self::one() + b.geometric_product(&a))
+            .unwrap_or_else(|| a.orthogonal().geometric_product(&a))
+    }
+
+    /// The rotor turning the plane of 2-blade `a` into that of `b` with its orientation, the smallest such
+    /// rotation.
+    ///
+    /// Oppositely oriented planes are turned half way round about a line in the plane.
+    pub fn between_bivectors(a: &Self, b: &Self) -> Self {
+        let (a, b) = (a.normalized(), b.normalized());
+        Self::unit_rotor(Self::one() + b.geometric_product(&a.reverse()))
+            .unwrap_or_else(|| a.orthogonal().geometric_product(&a.within()))
+    }
+}
+
+impl<T> Vga<T, 3> where
+T: Real,
+{
+    /// The rotor turning the camera convention of looking down `-z` with `y` up to looking along `forward`
+    /// with `up` as close to up as possible.
+    ///
+    /// When `up` is parallel to `forward`, the basis vector furthest from `forward` stands in for it.
+    pub fn look_at(forward: [T; 3], up: [T; 3]) -> Self {
+        let forward = Self::from_vector(forward).normalized();
+        let up = Self::from_vector(up);
+        let size = up.norm();
+        let up = up.clone() - forward.scale(&forward.inner_product(&up));
+        let up = if up.norm() <= T::epsilon().sqrt() * size { forward.orthogonal() } else { up.normalized() };
+        let first = Self::between(&-Self::basis(0b100), &forward);
+        let turned = first.sandwich(&Self::basis(0b010));
+        let second = Self::unit_rotor(Self::one() + up.geometric_product(&turned))
+            .unwrap_or_else(|| forward.geometric_product(&Self::basis(0b111)));
+        second.geometric_product(&first)
+    }
+}
+
+impl<T> Motor<T> where
+T: Real,
+{
+    /// The motor placing a camera at `eye`, looking at `target` with `up` as close to up as possible, in the
+    /// convention of looking down `-z` with `y` up.
+    pub fn look_at(eye: [T; 3], target: [T; 3], up: [T; 3]) -> Self {
+        let forward = core::array::from_fn(|i| target[i].clone() - eye[i].clone());
+        Self::from_rotation_translation(&Vga::<T, 3>::look_at(forward, up), eye)
+    }
+}
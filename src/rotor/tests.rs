@@ -0,0 +1,75 @@
+use crate::{Motor, Pga, Rotor, Vga};
+use crate::tests::{close, same};
+
+#[test]
+fn between_vectors() {
+    type V = Vga<f64, 3>;
+    let (a, b) = (V::from_vector([1.0, 2.0, -1.0]), V::from_vector([-3.0, 0.5, 2.0]));
+    let r = Rotor::between(&a, &b);
+    assert!(close(r.norm(), 1.0));
+    assert!(same(&r.sandwich(&a.normalized()), &b.normalized()));
+    let cos = a.normalized().inner_product(&b.normalized());
+    assert!(close(2.0 * r.scalar_part() * r.scalar_part() - 1.0, cos));
+    assert!(same(&Rotor::between(&a, &a.scale(&2.0)), &V::from_scalar(1.0)));
+
+    for a in [V::from_vector([1.0, 0.0, 0.0]), V::from_vector([1.0, 1.0, 1.0]), V::from_vector([0.0, 0.0, -2.0])] {
+        let r = Rotor::between(&a, &-a.clone());
+        assert!(close(r.norm(), 1.0));
+        assert!(same(&r.sandwich(&a), &-a.clone()));
+    }
+
+    type W = Vga<f64, 5>;
+    let a = W::from_vector([1.0, -1.0, 0.5, 2.0, 0.0]);
+    let b = W::from_vector([0.0, 3.0, 1.0, -1.0, 1.0]);
+    assert!(same(&Rotor::between(&a, &b).sandwich(&a.normalized()), &b.normalized()));
+    assert!(same(&Rotor::between(&a, &-a.clone()).sandwich(&a), &-a.clone()));
+
+    type Plane = Vga<f64, 2>;
+    let a = Plane::from_vector([0.6, 0.8]);
+    assert!(same(&Rotor::between(&a, &-a.clone()).sandwich(&a), &-a.clone()));
+}
+
+#[test]
+fn between_planes() {
+    type V = Vga<f64, 3>;
+    let a = V::from_vector([1.0, 2.0, 0.0]).outer_product(&V::from_vector([0.0, 1.0, 1.0]));
+    let b = V::from_vector([-1.0, 0.0, 2.0]).outer_product(&V::from_vector([1.0, 1.0, 1.0]));
+    let r = Rotor::between_bivectors(&a, &b);
+    assert!(same(&r.sandwich(&a.normalized()), &b.normalized()));
+    assert!(same(&r, &Rotor::between(&a.dual(), &b.dual())));
+    assert!(same(&Rotor::between_bivectors(&a, &-a.clone()).sandwich(&a), &-a.clone()));
+
+    type W = Vga<f64, 4>;
+    let a = W::from_vector([1.0, 0.2, -0.3, 0.5]).outer_product(&W::from_vector([0.1, 1.0, 0.7, -0.2]));
+    let b = W::from_vector([-0.4, 0.3, 1.0, 0.2]).outer_product(&W::from_vector([0.5, 0.1, 0.3, 1.0]));
+    let r = Rotor::between_bivectors(&a, &b);
+    assert!(close(r.norm(), 1.0));
+    assert!(same(&r.sandwich(&a.normalized()), &b.normalized()));
+    assert!(same(&Rotor::between_bivectors(&a, &-a.clone()).sandwich(&a), &-a.clone()));
+}
+
+#[test]
+fn look_at() {
+    type V = Vga<f64, 3>;
+    let (forward, up) = ([1.0, 2.0, -2.0], [0.0, 0.0, 1.0]);
+    let r = V::look_at(forward, up);
+    assert!(same(&r.sandwich(&V::basis(0b100)), &V::from_vector([-1.0, -2.0, 2.0]).scale(&(1.0 / 3.0))));
+    let turned_up = r.sandwich(&V::basis(0b010));
+    assert!(close(turned_up.inner_product(&V::from_vector(forward)), 0.0));
+    assert!(close(turned_up.outer_product(&V::from_vector(forward)).outer_product(&V::from_vector(up)).norm(), 0.0));
+    assert!(turned_up.inner_product(&V::from_vector(up)) > 0.0);
+
+    let flipped = V::look_at([0.0, 0.0, -1.0], [0.0, -1.0, 0.0]);
+    assert!(same(&flipped.sandwich(&V::basis(0b010)), &-V::basis(0b010)));
+    assert!(same(&flipped.sandwich(&V::basis(0b100)), &V::basis(0b100)));
+
+    // With `up` along `forward` any up will do, but the rotor still looks along `forward`.
+    let straight = V::look_at([0.0, 0.0, 1.0], [0.0, 0.0, 2.0]);
+    assert!((0..8).all(|i| straight[i].is_finite()));
+    assert!(close(straight.norm(), 1.0));
+    assert!(same(&straight.sandwich(&V::basis(0b100)), &-V::basis(0b100)));
+
+    let camera = Motor::look_at([1.0, 1.0, 1.0], [1.0, 4.0, 5.0], [0.0, 0.0, 1.0]);
+    let (x, y, z) = camera.transform_point(&Pga::<f64, 3>::point(0.0, 0.0, -5.0)).to_point().unwrap();
+    assert!(close(x, 1.0) && close(y, 4.0) && close(z, 5.0));
+}
@@ -0,0 +1,158 @@
+use crate::{Motor, Pga};
+use crate::multivector::{Real, Zero};
+
+#[cfg(test)]
+mod tests;
+
+
+/// Evaluate `start * exp(w[0] * steps[0]) * exp(w[1] * steps[1]) * ...` for the cumulative basis weights `w`,
+/// and its derivative from the derivatives `dw` of the weights.
+///
+/// Each factor `exp(w * B)` has the derivative `exp(w * B) * dw * B`, so the product rule gives the
+/// derivative of the whole product exactly.
+fn cumulative<T: Real>(start: &Motor<T>, steps: &[Pga<T, 3>], w: &[T], dw: &[T]) -> (Motor<T>, Motor<T>) {
+    let factors: Vec<Motor<T>> = steps.iter().zip(w).map(|(b, w)| Motor::exp(&b.scale(w))).collect();
+    let mut value = start.clone();
+    let mut derivative = Motor::zero();
+    for (i, factor) in factors.iter().enumerate() {
+        let tangent = factor * &Motor::from_even(&steps[i].scale(&dw[i]));
+        let mut term = &value * &tangent;
+        for later in &factors[i + 1..] {
+            term = &term * later;
+        }
+        derivative = derivative + term;
+        value = &value * factor;
+    }
+    (value, derivative)
+}
+
+/// The steps `log(controls[i - 1].reverse() * controls[i])` between consecutive motors.
+fn steps<T: Real>(controls: &[Motor<T>]) -> Vec<Pga<T, 3>> {
+    controls.windows(2).map(|pair| (&pair[0].reverse() * &pair[1]).log()).collect()
+}
+
+/// The bivector `B` with `derivative == value * B`, the velocity of a rigid motion in its own moving frame.
+fn velocity<T: Real>(value: &Motor<T>, derivative: &Motor<T>) -> Pga<T, 3> {
+    Pga::from(&value.inverse() * derivative).grade(2)
+}
+
+fn binomial<T: Real>(n: usize, k: usize) -> T {
+    (0..k).fold(T::one(), |c, i| c * T::from_f64((n - i) as f64) / T::from_f64((i + 1) as f64))
+}
+
+fn power<T: Real>(x: &T, n: usize) -> T {
+    (0..n).fold(T::one(), |p, _| p * x.clone())
+}
+
+/// A Bézier curve of rigid motions, blending the control motors in the motor Lie algebra.
+///
+/// This is the cumulative form of the Bernstein basis, which like De Casteljau's construction passes through
+/// the first and last control motor, leaves them tangent to the first and last step, and reduces to the
+/// ordinary Bézier curve for pure translations.
+#[derive(Clone, Debug)]
+pub struct Bezier<T> {
+    start: Motor<T>,
+    steps: Vec<Pga<T, 3>>,
+}
+
+impl<T> Bezier<T> where
+T: Real,
+{
+    /// The curve through the control motors, which need at least two.
+    pub fn new(controls: &[Motor<T>]) -> Self {
+        assert!(controls.len() >= 2, "a Bézier curve needs at least two control motors");
+        Self {
+            start: controls[0].normalized(),
+            steps: steps(controls),
+        }
+    }
+
+    fn weights(&self, t: &T) -> (Vec<T>, Vec<T>) {
+        let n = self.steps.len();
+        let s = T::one() - t.clone();
+        let bernstein = |n: usize, k: usize| binomial::<T>(n, k) * power(t, k) * power(&s, n - k);
+        let w = (1..=n).map(|i| (i..=n).fold(T::zero(), |w, j| w + bernstein(n, j))).collect();
+        let dw = (1..=n).map(|i| T::from_f64(n as f64) * bernstein(n - 1, i - 1)).collect();
+        (w, dw)
+    }
+
+    /// The motor at `t` in `[0, 1]`.
+    pub fn evaluate(&self, t: T) -> Motor<T> {
+        self.evaluate_with_derivative(t).0
+    }
+
+    /// The motor at `t` and its derivative with respect to `t`.
+    pub fn evaluate_with_derivative(&self, t: T) -> (Motor<T>, Motor<T>) {
+        let (w, dw) = self.weights(&t);
+        cumulative(&self.start, &self.steps, &w, &dw)
+    }
+
+    /// The velocity at `t` as the bivector `B` with `derivative == value * B`, in the moving frame.
+    pub fn velocity(&self, t: T) -> Pga<T, 3> {
+        let (value, derivative) = self.evaluate_with_derivative(t);
+        velocity(&value, &derivative)
+    }
+}
+
+/// A uniform cubic B-spline of rigid motions, blending the control motors in the motor Lie algebra.
+///
+/// This is the cumulative B-spline of Kim, Kim and Shin, which is twice continuously differentiable and
+/// reduces to the ordinary B-spline for pure translations. Like it, the curve does not pass through the
+/// control motors.
+#[derive(Clone, Debug)]
+pub struct BSpline<T> {
+    controls: Vec<Motor<T>>,
+    steps: Vec<Pga<T, 3>>,
+}
+
+impl<T> BSpline<T> where
+T: Real,
+{
+    /// The curve over the control motors, which need at least four.
+    pub fn new(controls: &[Motor<T>]) -> Self {
+        assert!(controls.len() >= 4, "a cubic B-spline needs at least four control motors");
+        Self {
+            controls: controls.iter().map(Motor::normalized).collect(),
+            steps: steps(controls),
+        }
+    }
+
+    /// The parameter runs over `[0, segments]`, one unit per segment.
+    pub fn segments(&self) -> usize {
+        self.controls.len() - 3
+    }
+
+    /// The motor at `t` in `[0, self.segments()]`.
+    pub fn evaluate(&self, t: T) -> Motor<T> {
+        self.evaluate_with_derivative(t).0
+    }
+
+    /// The motor at `t` and its derivative with respect to `t`.
+    pub fn evaluate_with_derivative(&self, t: T) -> (Motor<T>, Motor<T>) {
+        let mut i = 0;
+        while i + 1 < self.segments() && T::from_f64((i + 1) as f64) <= t {
+            i += 1;
+        }
+        let u = t - T::from_f64(i as f64);
+        let sixth = T::one() / T::from_f64(6.0);
+        let [u2, u3] = [power(&u, 2), power(&u, 3)];
+        let f = |x: f64| T::from_f64(x);
+        let w = [
+            (f(5.0) + f(3.0) * u.clone() - f(3.0) * u2.clone() + u3.clone()) * sixth.clone(),
+            (f(1.0) + f(3.0) * u.clone() + f(3.0) * u2.clone() - f(2.0) * u3.clone()) * sixth.clone(),
+            u3 * sixth.clone(),
+        ];
+        let dw = [
+            (f(3.0) - f(6.0) * u.clone() + f(3.0) * u2.clone()) * sixth.clone(),
+            (f(3.0) + f(6.0) * u.clone() - f(6.0) * u2.clone()) * sixth.clone(),
+            f(3.0) * u2 * sixth,
+        ];
+        cumulative(&self.controls[i], &self.steps[i..i + 3], &w, &dw)
+    }
+
+    /// The velocity at `t` as the bivector `B` with `derivative == value * B`, in the moving frame.
+    pub fn velocity(&self, t: T) -> Pga<T, 3> {
+        let (value, derivative) = self.evaluate_with_derivative(t);
+        velocity(&value, &derivative)
+    }
+}
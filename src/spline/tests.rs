@@ -0,0 +1,100 @@
+use crate::{BSpline, Bezier, Motor, Pga};
+use crate::tests::close;
+use core::f64::consts::FRAC_PI_2;
+
+type P = Pga<f64, 3>;
+
+/// Equal up to sign, as `m` and `-m` are the same motion.
+fn same_motion(lhs: &Motor<f64>, rhs: &Motor<f64>) -> bool {
+    let sign = if lhs.coefficients()[0] * rhs.coefficients()[0] < 0.0 { -1.0 } else { 1.0 };
+    lhs.coefficients().iter().zip(rhs.coefficients()).all(|(a, b)| close(*a, sign * b))
+}
+
+fn at(p: &P, x: f64, y: f64, z: f64) -> bool {
+    let (u, v, w) = p.to_point().unwrap();
+    close(u, x) && close(v, y) && close(w, z)
+}
+
+fn controls() -> Vec<Motor<f64>> {
+    let line = P::point(1.0, 0.0, 0.0).line_through(&P::point(1.0, 1.0, 2.0));
+    vec![
+        Motor::identity(),
+        Motor::from_axis_angle([0.0, 0.0, 1.0], 0.8) * Motor::from_translation(1.0, 0.0, 0.0),
+        Motor::from_screw(&line, 2.0, 0.4),
+        Motor::from_translation(0.0, 3.0, -1.0) * Motor::from_axis_angle([1.0, 1.0, 0.0], -1.2),
+        Motor::from_translation(2.0, 2.0, 2.0),
+    ]
+}
+
+/// The derivative by central differences.
+fn difference<F: Fn(f64) -> Motor<f64>>(f: F, t: f64) -> Motor<f64> {
+    let h = 1e-6;
+    let (a, b) = (f(t + h), f(t - h));
+    Motor::from(core::array::from_fn(|i| (a.coefficients()[i] - b.coefficients()[i]) / (2.0 * h)))
+}
+
+#[test]
+fn exp_and_log() {
+    let z_axis = P::point(0.0, 0.0, 0.0).line_through(&P::point(0.0, 0.0, 1.0));
+    let generator = z_axis.scale(&(FRAC_PI_2 / 2.0));
+    assert!(same_motion(&Motor::exp(&generator), &Motor::from_even(&P::rotor(&z_axis, FRAC_PI_2))));
+    for m in controls() {
+        let b = m.log();
+        assert!(same_motion(&Motor::exp(&b), &m));
+        assert!(same_motion(&Motor::exp(&b), &Motor::from_even(&b.exp())));
+    }
+    let tiny = P::from_plucker([1e-9, 0.0, 0.0], [0.3, 0.2, 1e-9]);
+    assert!(same_motion(&Motor::exp(&tiny), &Motor::from_even(&tiny.exp())));
+    assert!(same_motion(&Motor::exp(&tiny.scale(&2.0)), &(Motor::exp(&tiny) * Motor::exp(&tiny))));
+}
+
+#[test]
+fn screw_linear_interpolation() {
+    let axis = P::point(1.0, 0.0, 0.0).line_through(&P::point(1.0, 0.0, 1.0));
+    let a = Motor::identity();
+    let b = Motor::from_screw(&axis, FRAC_PI_2, 2.0);
+    assert!(same_motion(&a.sclerp(&b, 0.0), &a));
+    assert!(same_motion(&a.sclerp(&b, 1.0), &b));
+    assert!(same_motion(&a.sclerp(&b, 0.5), &Motor::from_screw(&axis, FRAC_PI_2 / 2.0, 2.0)));
+    let (c, d) = (controls()[1], controls()[3]);
+    assert!(same_motion(&c.sclerp(&d, 0.3), &d.sclerp(&c, 0.7)));
+}
+
+#[test]
+fn bezier() {
+    let controls = controls();
+    let curve = Bezier::new(&controls);
+    assert!(same_motion(&curve.evaluate(0.0), &controls[0]));
+    assert!(same_motion(&curve.evaluate(1.0), &controls[4]));
+    assert!(same_motion(&Bezier::new(&controls[1..3]).evaluate(0.4), &controls[1].sclerp(&controls[2], 0.4)));
+
+    let translations: Vec<_> = [[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [2.0, 2.0, 0.0]].iter()
+        .map(|[x, y, z]| Motor::from_translation(*x, *y, *z))
+        .collect();
+    let origin = P::point(0.0, 0.0, 0.0);
+    assert!(at(&Bezier::new(&translations).evaluate(0.5).apply(&origin), 1.5, 0.5, 0.0));
+
+    for t in [0.0, 0.3, 0.8, 1.0] {
+        let (_, derivative) = curve.evaluate_with_derivative(t);
+        assert!(same_motion(&derivative, &difference(|t| curve.evaluate(t), t)));
+    }
+}
+
+#[test]
+fn b_spline() {
+    let controls = controls();
+    let curve = BSpline::new(&controls);
+    assert_eq!(curve.segments(), 2);
+    for t in [0.0, 0.4, 1.0, 1.7, 2.0] {
+        let (value, derivative) = curve.evaluate_with_derivative(t);
+        assert!(close(value.normalized().coefficients()[0], value.coefficients()[0]));
+        assert!(same_motion(&derivative, &difference(|t| curve.evaluate(t), t)));
+        let velocity = curve.velocity(t);
+        assert!(same_motion(&(value * Motor::from_even(&velocity)), &derivative));
+    }
+
+    let translations: Vec<_> = [0.0, 6.0, 0.0, 6.0].iter().map(|x| Motor::from_translation(*x, 0.0, 0.0)).collect();
+    let origin = P::point(0.0, 0.0, 0.0);
+    assert!(at(&BSpline::new(&translations).evaluate(0.0).apply(&origin), 4.0, 0.0, 0.0));
+    assert!(at(&BSpline::new(&translations).evaluate(0.5).apply(&origin), 3.0, 0.0, 0.0));
+}
@@ -0,0 +1,208 @@
+use crate::{Dual, Spacetime, jacobian};
+use crate::multivector::{Clifford, Real, Zero, spacetime};
+
+#[cfg(test)]
+mod tests;
+
+
+/// The bit of `gamma(mu)` with the time vector first or last.
+const fn gamma_bit(time_first: bool, mu: usize) -> usize {
+    if time_first { 1 << mu } else { 1 << ((mu + 3) % 4) }
+}
+
+/// The indices `mu` of the vectors of a basis blade as a bit set, and whether the blade is the negative of
+/// their product in increasing order, which happens when the time vector is last and has to move past an
+/// odd number of space vectors.
+const fn gamma_indices(time_first: bool, bits: usize) -> (usize, bool) {
+    if time_first {
+        (bits, false)
+    } else {
+        let indices = (bits << 1 | bits >> 3) & 0b1111;
+        (indices, bits & 0b1000 != 0 && Clifford::grade(bits) & 1 == 0)
+    }
+}
+
+/// Special relativity and electromagnetism in the spacetime algebra, in units with `c = 1`, for each sign
+/// convention and placement of the time vector.
+///
+/// The observer at rest is `gamma(0)` and `gamma(1..=3)` span space. Relative vectors of the rest frame are
+/// the bivectors `sigma_k = gamma(k) * gamma(0)`, which square to `1` like the vectors of 3D space in either
+/// convention, and the pseudoscalar `I = gamma(0) * gamma(1) * gamma(2) * gamma(3)` squares to `-1`. Lorentz
+/// transformations are rotors, applied with `sandwich`.
+macro_rules! impl_spacetime {
+    ($($mostly_plus:literal $time_first:literal)*) => {
+        $(
+            impl<T> Spacetime<T, $mostly_plus, $time_first> where
+            T: Real,
+            {
+                /// The basis vector `gamma_mu`, with `gamma(0)` the time axis.
+                pub fn gamma(mu: usize) -> Self {
+                    Self::basis(gamma_bit($time_first, mu))
+                }
+
+                /// The square of `gamma(0)`, `1` for `+---` and `-1` for `-+++`.
+                fn time_sign() -> T {
+                    if $mostly_plus { T::one().neg() } else { T::one() }
+                }
+
+                /// The same element in another convention, with the same components on the products of
+                /// `gamma(mu)` in increasing order.
+                ///
+                /// Vectors and bivectors keep their physical meaning, though squares and inner products of
+                /// vectors change sign between `+---` and `-+++`.
+                pub fn to_convention<const MOSTLY_PLUS: bool, const TIME_FIRST: bool>(&self) -> Spacetime<T, MOSTLY_PLUS, TIME_FIRST> where
+                [(); spacetime(MOSTLY_PLUS, TIME_FIRST).size()]: Sized,
+                {
+                    let mut x = Spacetime::<T, MOSTLY_PLUS, TIME_FIRST>::zero();
+                    for bits in 0..16 {
+                        let (indices, flip) = gamma_indices($time_first, bits);
+                        let target = (0..4)
+                            .filter(|mu| indices & 1 << mu != 0)
+                            .fold(0, |b, mu| b | gamma_bit(TIME_FIRST, mu));
+                        let v = self.coefficient(bits).clone();
+                        *x.coefficient_mut(target) = if flip != gamma_indices(TIME_FIRST, target).1 { v.neg() } else { v };
+                    }
+                    x
+                }
+
+                /// The spacetime vector `t gamma(0) + x gamma(1) + y gamma(2) + z gamma(3)`.
+                pub fn event(t: T, x: T, y: T, z: T) -> Self {
+                    [t, x, y, z].iter().enumerate().fold(Self::zero(), |v, (mu, x)| v + Self::gamma(mu).scale(x))
+                }
+
+                /// The relative vector `x[k] sigma_k` of the rest frame.
+                pub fn relative_vector(x: [T; 3]) -> Self {
+                    x.iter().enumerate().fold(Self::zero(), |v, (k, x)| v + Self::sigma(k).scale(x))
+                }
+
+                fn sigma(k: usize) -> Self {
+                    Self::gamma(k + 1).geometric_product(&Self::gamma(0))
+                }
+
+                fn pseudoscalar() -> Self {
+                    (1..4).fold(Self::gamma(0), |i, mu| i.geometric_product(&Self::gamma(mu)))
+                }
+
+                /// The components of the relative vector part of a bivector in the rest frame.
+                fn to_relative_vector(&self) -> [T; 3] {
+                    core::array::from_fn(|k| self.inner_product(&Self::sigma(k)))
+                }
+
+                /// The rotor boosting by `rapidity` along `direction`, `exp(rapidity sigma_n / 2)` in `+---` and
+                /// `exp(-rapidity sigma_n / 2)` in `-+++`.
+                pub fn boost(direction: [T; 3], rapidity: T) -> Self {
+                    let n = direction.iter().fold(T::zero(), |n, x| n + x.clone() * x.clone()).sqrt();
+                    let half = rapidity * Self::time_sign() / n * T::from_f64(0.5);
+                    Self::relative_vector(direction).scale(&half).exp()
+                }
+
+                /// The rotor turning space by `angle` about `axis`, counterclockwise when looking against it.
+                pub fn rotation(axis: [T; 3], angle: T) -> Self {
+                    let n = axis.iter().fold(T::zero(), |n, x| n + x.clone() * x.clone()).sqrt();
+                    let plane = (0..3).fold(Self::zero(), |b, k| {
+                        let (i, j) = ((k + 1) % 3, (k + 2) % 3);
+                        b - Self::sigma(i).geometric_product(&Self::sigma(j)).scale(&axis[k])
+                    });
+                    plane.scale(&(angle / n * T::from_f64(0.5))).exp()
+                }
+
+                /// The rapidity of a speed, `atanh(speed)`.
+                pub fn rapidity(speed: T) -> T {
+                    ((T::one() + speed.clone()) / (T::one() - speed)).ln() * T::from_f64(0.5)
+                }
+
+                /// The rapidity between two observers given by their four-velocities.
+                pub fn relative_rapidity(&self, other: &Self) -> T {
+                    let x = self.inner_product(other) * Self::time_sign();
+                    (x.clone() + (x.clone() * x - T::one()).sqrt()).ln()
+                }
+
+                /// The squared interval `self . self` of a displacement, which has the sign of
+                /// `gamma(0) * gamma(0)` for timelike ones.
+                pub fn interval(&self) -> T {
+                    self.inner_product(self)
+                }
+
+                /// The proper time along a timelike displacement, `None` for spacelike ones.
+                pub fn proper_time(&self) -> Option<T> {
+                    let interval = self.interval() * Self::time_sign();
+                    if interval < T::zero() {
+                        None
+                    } else {
+                        Some(interval.sqrt())
+                    }
+                }
+
+                /// The four-velocity `(gamma(0) + v) / sqrt(1 - v^2)` of a relative velocity `v` of the rest
+                /// frame.
+                pub fn four_velocity(v: [T; 3]) -> Self {
+                    let square = v.iter().fold(T::zero(), |n, x| n + x.clone() * x.clone());
+                    let [x, y, z] = v;
+                    Self::event(T::one(), x, y, z).scale(&(T::one() / (T::one() - square).sqrt()))
+                }
+
+                /// The velocity relative to the rest frame of a four-velocity or timelike displacement.
+                pub fn to_velocity(&self) -> [T; 3] {
+                    let [t, x, y, z] = [0, 1, 2, 3].map(|mu| self.coefficient(gamma_bit($time_first, mu)).clone());
+                    [x / t.clone(), y / t.clone(), z / t]
+                }
+
+                /// The pure boost taking the rest frame `gamma(0)` to the observer with four-velocity `self`.
+                fn rest_frame_boost(&self) -> Self {
+                    let s = Self::time_sign();
+                    let r = self.geometric_product(&Self::gamma(0)).scale(&s) + T::one();
+                    let n = ((self.inner_product(&Self::gamma(0)) * s + T::one()) * T::from_f64(2.0)).sqrt();
+                    r.scale(&(T::one() / n))
+                }
+
+                /// The relativistic sum of velocities, the velocity of an object moving at `v` relative to an
+                /// observer who moves at `u`, both relative to the rest frame.
+                ///
+                /// This is not commutative; `add_velocities(v, u)` differs from it by a Thomas-Wigner rotation.
+                pub fn add_velocities(u: [T; 3], v: [T; 3]) -> [T; 3] {
+                    Self::four_velocity(u).rest_frame_boost().sandwich(&Self::four_velocity(v)).to_velocity()
+                }
+
+                /// The Faraday bivector `F = E + I B` of electric and magnetic fields in the rest frame.
+                pub fn faraday(e: [T; 3], b: [T; 3]) -> Self {
+                    Self::relative_vector(e) + Self::pseudoscalar().geometric_product(&Self::relative_vector(b))
+                }
+
+                /// The electric and magnetic fields of a Faraday bivector as measured by the observer with
+                /// four-velocity `observer`, in the frame of the pure boost taking the rest frame to the observer.
+                pub fn spacetime_split(&self, observer: &Self) -> ([T; 3], [T; 3]) {
+                    let boost = observer.rest_frame_boost();
+                    let f = boost.reverse().sandwich(self);
+                    let e = f.to_relative_vector();
+                    let b = (-f.geometric_product(&Self::pseudoscalar())).to_relative_vector();
+                    (e, b)
+                }
+
+                /// The four-current `rho gamma(0) + j[k] gamma(k)` of charge density `rho` and current density `j`.
+                pub fn current(rho: T, j: [T; 3]) -> Self {
+                    let [x, y, z] = j;
+                    Self::event(rho, x, y, z)
+                }
+
+                /// The vector derivative `gamma^mu d_mu` of a field at the event `x`, by forward-mode
+                /// differentiation.
+                ///
+                /// In this form Maxwell's equations are the single equation `vector_derivative(F, x) == J` for
+                /// the Faraday bivector `F` and the four-current `J`.
+                pub fn vector_derivative<F>(field: F, x: &Self) -> Self where
+                F: Fn(&Spacetime<Dual<T>, $mostly_plus, $time_first>) -> Spacetime<Dual<T>, $mostly_plus, $time_first>,
+                {
+                    let columns = jacobian(field, x);
+                    (0..4).fold(Self::zero(), |d, mu| {
+                        let gamma = Self::gamma(mu);
+                        let reciprocal = gamma.scale(&(T::one() / gamma.interval()));
+                        let blade = spacetime($mostly_plus, $time_first).bit_to_blade(gamma_bit($time_first, mu));
+                        d + reciprocal.geometric_product(&columns[blade])
+                    })
+                }
+            }
+        )*
+    }
+}
+
+impl_spacetime!(false true false false true true true false);
@@ -0,0 +1,157 @@
+use crate::{Dual, Real, Spacetime, Sta, Zero};
+use crate::tests::{close, same};
+
+type S = Sta<f64>;
+
+fn same3(lhs: [f64; 3], rhs: [f64; 3]) -> bool {
+    (0..3).all(|i| close(lhs[i], rhs[i]))
+}
+
+#[test]
+fn boosts_and_rotations() {
+    let phi = S::rapidity(0.6);
+    assert!(close(phi.tanh(), 0.6));
+    let l = S::boost([0.0, 2.0, 0.0], phi);
+    assert!(close(l.norm_squared(), 1.0));
+    let u = l.sandwich(&S::gamma(0));
+    assert!(same(&u, &S::four_velocity([0.0, 0.6, 0.0])));
+    assert!(same(&u, &S::event(1.25, 0.0, 0.75, 0.0)));
+    assert!(close(u.relative_rapidity(&S::gamma(0)), phi));
+    assert!(close(u.interval(), 1.0));
+
+    let r = S::rotation([0.0, 0.0, 1.0], core::f64::consts::FRAC_PI_2);
+    assert!(same(&r.sandwich(&S::gamma(1)), &S::gamma(2)));
+    assert!(same(&r.sandwich(&S::gamma(0)), &S::gamma(0)));
+    let r = S::rotation([1.0, 1.0, 1.0], 2.0 * core::f64::consts::FRAC_PI_3);
+    assert!(same(&r.sandwich(&S::gamma(1)), &S::gamma(2)));
+
+    let x = S::event(3.0, 1.0, -2.0, 0.5);
+    let moved = r.geometric_product(&l).sandwich(&x);
+    assert!(close(moved.interval(), x.interval()));
+    assert!(close(x.proper_time().unwrap(), (9.0f64 - 1.0 - 4.0 - 0.25).sqrt()));
+    assert!(S::event(1.0, 2.0, 0.0, 0.0).proper_time().is_none());
+}
+
+#[test]
+fn velocity_addition() {
+    let w = S::add_velocities([0.5, 0.0, 0.0], [0.5, 0.0, 0.0]);
+    assert!(same3(w, [0.8, 0.0, 0.0]));
+    let w = S::add_velocities([0.6, 0.0, 0.0], [0.0, 0.5, 0.0]);
+    assert!(same3(w, [0.6, 0.4, 0.0]));
+    let w = S::add_velocities([0.6, -0.2, 0.7], [0.0, 0.0, 0.99]);
+    assert!(w.iter().map(|x| x * x).sum::<f64>() < 1.0);
+
+    let (u, v) = ([0.3, -0.2, 0.4], [-0.5, 0.1, 0.6]);
+    let phi = S::four_velocity(v).relative_rapidity(&S::gamma(0));
+    assert!(close(S::four_velocity(S::add_velocities(u, v)).relative_rapidity(&S::four_velocity(u)), phi));
+}
+
+#[test]
+fn fields() {
+    let (e, b) = ([1.0, -2.0, 0.5], [0.3, 0.0, -1.5]);
+    let f = S::faraday(e, b);
+    assert!(same(&f.grade(2), &f));
+    let (e2, b2) = f.spacetime_split(&S::gamma(0));
+    assert!(same3(e, e2) && same3(b, b2));
+
+    // A field moving past at `v` along x: E' = (Ex, g (Ey - v Bz), g (Ez + v By)),
+    // B' = (Bx, g (By + v Ez), g (Bz - v Ey)).
+    let (v, g) = (0.6, 1.25);
+    let (e2, b2) = f.spacetime_split(&S::four_velocity([v, 0.0, 0.0]));
+    assert!(same3(e2, [e[0], g * (e[1] - v * b[2]), g * (e[2] + v * b[1])]));
+    assert!(same3(b2, [b[0], g * (b[1] + v * e[2]), g * (b[2] - v * e[1])]));
+
+    let invariant = |(e, b): ([f64; 3], [f64; 3])| (0..3).map(|k| e[k] * e[k] - b[k] * b[k]).sum::<f64>();
+    assert!(close(invariant(f.spacetime_split(&S::four_velocity([0.1, 0.5, -0.7]))), invariant((e, b))));
+}
+
+#[test]
+fn maxwell() {
+    type D = Sta<Dual<f64>>;
+    let at = S::event(0.3, 1.0, -0.5, 2.0);
+
+    // A plane wave along z, E along x and B along y, is a vacuum solution.
+    let wave = |x: &D| {
+        let phase = (x[4].clone() - x[1].clone()).sin();
+        D::faraday([phase.clone(), Dual::constant(0.0), Dual::constant(0.0)], [Dual::constant(0.0), phase, Dual::constant(0.0)])
+    };
+    assert!(same(&S::vector_derivative(wave, &at), &S::zero()));
+
+    // Gauss's law inside a uniformly charged ball, E = rho r / 3.
+    let rho = 1.5;
+    let ball = |x: &D| {
+        let k = Dual::constant(rho / 3.0);
+        D::faraday([x[2].clone() * k.clone(), x[3].clone() * k.clone(), x[4].clone() * k], [Dual::constant(0.0), Dual::constant(0.0), Dual::constant(0.0)])
+    };
+    assert!(same(&S::vector_derivative(ball, &at), &S::current(rho, [0.0; 3])));
+
+    // Ampere's law around a wire along z carrying current i, B = i (-y, x) / 2 inside it.
+    let i = 0.8;
+    let wire = |x: &D| {
+        let k = Dual::constant(i / 2.0);
+        D::faraday([Dual::constant(0.0), Dual::constant(0.0), Dual::constant(0.0)], [-x[3].clone() * k.clone(), x[2].clone() * k, Dual::constant(0.0)])
+    };
+    assert!(same(&S::vector_derivative(wire, &at), &S::current(0.0, [0.0, 0.0, i])));
+}
+
+#[test]
+fn conventions() {
+    type M = Spacetime<f64, true, true>;
+    type L = Spacetime<f64, false, false>;
+    type N = Spacetime<f64, true, false>;
+    let square = |x: &M| x.inner_product(x);
+    assert_eq!(S::gamma(0), S::basis(0b0001));
+    assert_eq!(M::gamma(0), M::basis(0b0001));
+    assert_eq!(L::gamma(0), L::basis(0b1000));
+    assert_eq!(N::gamma(0), N::basis(0b1000));
+    assert!(close(square(&M::gamma(0)), -1.0) && close(square(&M::gamma(2)), 1.0));
+    assert!(close(S::gamma(0).interval(), 1.0) && close(S::gamma(3).interval(), -1.0));
+    assert!(close(L::gamma(0).interval(), 1.0) && close(L::gamma(1).interval(), -1.0));
+    assert!(close(N::gamma(0).interval(), -1.0) && close(N::gamma(1).interval(), 1.0));
+
+    let x = S::event(2.0, 1.0, -3.0, 0.5);
+    let y = S::event(-1.0, 0.0, 4.0, 2.0);
+    let f = S::faraday([1.0, -2.0, 0.5], [0.3, 0.0, -1.5]);
+    let mixed = x.clone() + f.clone() + x.outer_product(&y).outer_product(&S::gamma(2)) + S::from_scalar(3.0);
+    assert_eq!(x.to_convention::<true, true>(), M::event(2.0, 1.0, -3.0, 0.5));
+    assert_eq!(x.to_convention::<false, false>(), L::event(2.0, 1.0, -3.0, 0.5));
+    assert_eq!(f.to_convention::<true, false>(), N::faraday([1.0, -2.0, 0.5], [0.3, 0.0, -1.5]));
+    assert_eq!(f.to_convention::<false, false>(), L::faraday([1.0, -2.0, 0.5], [0.3, 0.0, -1.5]));
+    assert!(close(x.to_convention::<true, false>().interval(), -x.interval()));
+    assert_eq!(mixed.to_convention::<true, false>().to_convention::<false, false>().to_convention::<false, true>(), mixed);
+
+    let product = x.geometric_product(&y);
+    let converted = x.to_convention::<false, false>().geometric_product(&y.to_convention::<false, false>());
+    assert_eq!(product.to_convention::<false, false>(), converted);
+}
+
+#[test]
+fn mostly_plus() {
+    type M = Spacetime<f64, true, true>;
+    let phi = M::rapidity(0.6);
+    let u = M::boost([0.0, 2.0, 0.0], phi).sandwich(&M::gamma(0));
+    assert!(same(&u.to_convention::<false, true>(), &S::four_velocity([0.0, 0.6, 0.0])));
+    assert!(close(u.relative_rapidity(&M::gamma(0)), phi));
+    assert!(close(M::event(3.0, 1.0, -2.0, 0.5).proper_time().unwrap(), (9.0f64 - 1.0 - 4.0 - 0.25).sqrt()));
+
+    let r = M::rotation([0.0, 0.0, 1.0], core::f64::consts::FRAC_PI_2);
+    assert!((0..16).all(|i| close(r.sandwich(&M::gamma(1))[i], M::gamma(2)[i])));
+    let w = M::add_velocities([0.6, 0.0, 0.0], [0.0, 0.5, 0.0]);
+    assert!(same3(w, [0.6, 0.4, 0.0]));
+
+    let (e, b) = ([1.0, -2.0, 0.5], [0.3, 0.0, -1.5]);
+    let observer = [0.1, 0.5, -0.7];
+    let (e1, b1) = M::faraday(e, b).spacetime_split(&M::four_velocity(observer));
+    let (e2, b2) = S::faraday(e, b).spacetime_split(&S::four_velocity(observer));
+    assert!(same3(e1, e2) && same3(b1, b2));
+
+    type D = Spacetime<Dual<f64>, true, true>;
+    let (rho, i) = (1.5, 0.8);
+    let field = |x: &D| {
+        let [x, y, z] = [2, 3, 4].map(|k| x[k].clone());
+        let (a, c) = (Dual::constant(rho / 3.0), Dual::constant(i / 2.0));
+        D::faraday([x.clone() * a.clone(), y.clone() * a.clone(), z * a], [-y * c.clone(), x * c, Dual::constant(0.0)])
+    };
+    let d = M::vector_derivative(field, &M::event(0.3, 1.0, -0.5, 2.0));
+    assert!((0..16).all(|k| close(d[k], M::current(rho, [0.0, 0.0, i])[k])));
+}
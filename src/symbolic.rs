@@ -0,0 +1,221 @@
+use crate::multivector::{Clifford, Multivector, One, Zero};
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+
+#[cfg(test)]
+mod tests;
+
+
+/// A named unknown, optionally indexed like the coefficient `a[3]` of a multivector `a`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol {
+    name: String,
+    index: Option<usize>,
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.index {
+            Some(i) => write!(f, "{}[{}]", self.name, i),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// A symbolic coefficient, simplified on construction to a sum of products of symbols with integer weights.
+///
+/// Keeping the sum-of-products normal form means like terms are collected and cancelling terms vanish,
+/// so two expressions are equal exactly when they are the same polynomial.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Expr {
+    terms: BTreeMap<Vec<Symbol>, i64>,
+}
+
+impl Expr {
+    pub fn symbol(name: &str) -> Self {
+        Self::monomial(Symbol { name: name.to_string(), index: None })
+    }
+
+    pub fn indexed(name: &str, index: usize) -> Self {
+        Self::monomial(Symbol { name: name.to_string(), index: Some(index) })
+    }
+
+    pub fn constant(x: i64) -> Self {
+        let mut terms = BTreeMap::new();
+        if x != 0 {
+            terms.insert(Vec::new(), x);
+        }
+        Self { terms }
+    }
+
+    fn monomial(symbol: Symbol) -> Self {
+        let mut terms = BTreeMap::new();
+        terms.insert(vec![symbol], 1);
+        Self { terms }
+    }
+
+    fn accumulate(&mut self, monomial: Vec<Symbol>, weight: i64) {
+        match self.terms.entry(monomial) {
+            Entry::Occupied(mut entry) => {
+                *entry.get_mut() += weight;
+                if *entry.get() == 0 {
+                    entry.remove();
+                }
+            }
+            Entry::Vacant(entry) => {
+                if weight != 0 {
+                    entry.insert(weight);
+                }
+            }
+        }
+    }
+
+    /// Render with `constant` formatting the integer weights, e.g. as floating point literals.
+    fn render<F>(&self, f: &mut fmt::Formatter, constant: F) -> fmt::Result where
+    F: Fn(i64) -> String,
+    {
+        if self.terms.is_empty() {
+            return write!(f, "{}", constant(0));
+        }
+        for (n, (monomial, weight)) in self.terms.iter().enumerate() {
+            if *weight < 0 {
+                write!(f, "-")?;
+            } else if n > 0 {
+                write!(f, "+")?;
+            }
+            let magnitude = weight.abs();
+            let mut separator = "";
+            if magnitude != 1 || monomial.is_empty() {
+                write!(f, "{}", constant(magnitude))?;
+                separator = "*";
+            }
+            for symbol in monomial {
+                write!(f, "{}{}", separator, symbol)?;
+                separator = "*";
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.render(f, |x| x.to_string())
+    }
+}
+
+impl Zero for Expr {
+    fn zero() -> Self {
+        Self::constant(0)
+    }
+}
+
+impl One for Expr {
+    fn one() -> Self {
+        Self::constant(1)
+    }
+}
+
+impl From<i64> for Expr {
+    fn from(x: i64) -> Self {
+        Self::constant(x)
+    }
+}
+
+impl AddAssign for Expr {
+    fn add_assign(&mut self, other: Self) {
+        for (monomial, weight) in other.terms {
+            self.accumulate(monomial, weight);
+        }
+    }
+}
+
+impl SubAssign for Expr {
+    fn sub_assign(&mut self, other: Self) {
+        for (monomial, weight) in other.terms {
+            self.accumulate(monomial, -weight);
+        }
+    }
+}
+
+impl Add for Expr {
+    type Output = Self;
+    fn add(mut self, other: Self) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl Sub for Expr {
+    type Output = Self;
+    fn sub(mut self, other: Self) -> Self::Output {
+        self -= other;
+        self
+    }
+}
+
+impl Neg for Expr {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        for weight in self.terms.values_mut() {
+            *weight = -*weight;
+        }
+        self
+    }
+}
+
+impl Mul for Expr {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        let mut x = Self::zero();
+        for (lhs, u) in &self.terms {
+            for (rhs, v) in &other.terms {
+                let mut monomial: Vec<Symbol> = lhs.iter().chain(rhs).cloned().collect();
+                monomial.sort();
+                x.accumulate(monomial, u * v);
+            }
+        }
+        x
+    }
+}
+
+impl<const C: Clifford> Multivector<Expr, C> where
+[(); C.size()]: Sized,
+{
+    /// The multivector whose coefficients are the unknowns `name[0], name[1], ...`.
+    pub fn symbols(name: &str) -> Self {
+        Self::from(core::array::from_fn(|i| Expr::indexed(name, i)))
+    }
+}
+
+/// An expression formatted with floating point literals.
+struct Source<'a>(&'a Expr);
+
+impl fmt::Display for Source<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.render(f, |x| format!("{}.0", x))
+    }
+}
+
+/// Print `result`, computed from the `symbols` named in `arguments`, as the source of a Rust function.
+///
+/// The generated function takes and returns coefficient arrays in the layout of `Multivector`, in the
+/// style of the ganja.js generated code.
+pub fn emit_rust<const C: Clifford>(function: &str, arguments: &[&str], result: &Multivector<Expr, C>) -> String where
+[(); C.size()]: Sized,
+{
+    let parameters: Vec<String> = arguments.iter()
+        .map(|name| format!("{}: &[f64; {}]", name, C.size()))
+        .collect();
+    let mut source = format!("pub fn {}({}) -> [f64; {}] {{\n", function, parameters.join(", "), C.size());
+    source += &format!("    let mut res = [0.0; {}];\n", C.size());
+    for i in 0..C.size() {
+        if result[i] != Expr::zero() {
+            source += &format!("    res[{}]={};\n", i, Source(&result[i]));
+        }
+    }
+    source += "    res\n}\n";
+    source
+}
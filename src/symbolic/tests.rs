@@ -0,0 +1,50 @@
+use crate::{Expr, Multivector, emit_rust};
+use crate::multivector::{Clifford, pga};
+
+const QUAT: Clifford = Clifford::new(0, 2, 0);
+
+#[test]
+fn simplification() {
+    let (x, y) = (Expr::symbol("x"), Expr::symbol("y"));
+    assert_eq!((x.clone() + y.clone()) * (x.clone() - y.clone()), x.clone() * x.clone() - y.clone() * y.clone());
+    assert_eq!(x.clone() * y.clone() - y.clone() * x.clone(), Expr::from(0));
+    assert_eq!((Expr::from(2) * x.clone() - y.clone()).to_string(), "2*x-y");
+}
+
+#[test]
+fn quaternion_product_formulas() {
+    let a = Multivector::<Expr, QUAT>::symbols("a");
+    let b = Multivector::<Expr, QUAT>::symbols("b");
+    let c = &a * &b;
+    assert_eq!(c[0].to_string(), "a[0]*b[0]-a[1]*b[1]-a[2]*b[2]-a[3]*b[3]");
+    assert_eq!(c[3].to_string(), "a[0]*b[3]+a[1]*b[2]-a[2]*b[1]+a[3]*b[0]");
+}
+
+#[test]
+fn symbolic_identities() {
+    const PGA2: Clifford = pga(2);
+    let a = Multivector::<Expr, PGA2>::symbols("a");
+    let b = Multivector::<Expr, PGA2>::symbols("b");
+    let c = Multivector::<Expr, PGA2>::symbols("c");
+    assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+    assert_eq!((&a * &b).reverse(), &b.reverse() * &a.reverse());
+}
+
+#[test]
+fn emitted_source() {
+    let a = Multivector::<Expr, QUAT>::symbols("a");
+    let b = Multivector::<Expr, QUAT>::symbols("b");
+    let mut c = &a ^ &b;
+    c[0] = c[0].clone() + Expr::from(2);
+    let expected = "\
+pub fn wedge(a: &[f64; 4], b: &[f64; 4]) -> [f64; 4] {
+    let mut res = [0.0; 4];
+    res[0]=2.0+a[0]*b[0];
+    res[1]=a[0]*b[1]+a[1]*b[0];
+    res[2]=a[0]*b[2]+a[2]*b[0];
+    res[3]=a[0]*b[3]+a[1]*b[2]-a[2]*b[1]+a[3]*b[0];
+    res
+}
+";
+    assert_eq!(emit_rust("wedge", &["a", "b"], &c), expected);
+}
@@ -0,0 +1,20 @@
+//! Comparisons shared by the test modules.
+
+use crate::{Clifford, Multivector};
+
+/// Whether two numbers agree up to rounding in a few steps of arithmetic.
+pub(crate) fn close(lhs: f64, rhs: f64) -> bool {
+    (lhs - rhs).abs() < 1e-9
+}
+
+/// Whether two numbers agree up to the rounding of a closed-form construction.
+pub(crate) fn exact(lhs: f64, rhs: f64) -> bool {
+    (lhs - rhs).abs() < 1e-12
+}
+
+/// Whether two multivectors are `close` in every coefficient.
+pub(crate) fn same<const C: Clifford>(lhs: &Multivector<f64, C>, rhs: &Multivector<f64, C>) -> bool where
+[(); C.size()]: Sized,
+{
+    (0..C.size()).all(|i| close(lhs[i], rhs[i]))
+}